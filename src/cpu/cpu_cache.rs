@@ -0,0 +1,192 @@
+use std::{cmp::Reverse, io::ErrorKind, path::Path};
+
+use crate::{cpu::parse_cpu_list, functions::read_one_value, scanner_rust::ScannerError};
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum CPUCacheType {
+    Data,
+    Instruction,
+    #[default]
+    Unified,
+}
+
+impl CPUCacheType {
+    #[allow(clippy::should_implement_trait)]
+    #[inline]
+    pub fn from_str<S: AsRef<str>>(s: S) -> Option<CPUCacheType> {
+        match s.as_ref() {
+            "Data" => Some(CPUCacheType::Data),
+            "Instruction" => Some(CPUCacheType::Instruction),
+            "Unified" => Some(CPUCacheType::Unified),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CPUCacheType::Data => "Data",
+            CPUCacheType::Instruction => "Instruction",
+            CPUCacheType::Unified => "Unified",
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct CPUCache {
+    pub level:                 u8,
+    pub cache_type:            CPUCacheType,
+    /// In bytes.
+    pub size:                  u64,
+    /// In bytes.
+    pub line_size:             Option<u32>,
+    pub ways_of_associativity: Option<u32>,
+    /// CPUs which share this cache.
+    pub shared_cpus:           Vec<usize>,
+    /// Lower-level caches which are shared by a subset of `shared_cpus`.
+    pub children:              Vec<CPUCache>,
+}
+
+/// `48K` -> `49152`
+#[inline]
+fn parse_size(size: &str) -> Result<u64, ScannerError> {
+    let (number, unit) = match size.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        Some((index, _)) => size.split_at(index),
+        None => (size, ""),
+    };
+
+    let number: u64 = number.parse()?;
+
+    match unit {
+        "" => Ok(number),
+        "K" => Ok(number * 1024),
+        "M" => Ok(number * 1024 * 1024),
+        "G" => Ok(number * 1024 * 1024 * 1024),
+        _ => Err(ErrorKind::InvalidData.into()),
+    }
+}
+
+#[inline]
+fn is_subset(cpus: &[usize], super_cpus: &[usize]) -> bool {
+    cpus.iter().all(|cpu| super_cpus.contains(cpu))
+}
+
+fn insert_cache(caches: &mut [CPUCache], cache: CPUCache) -> Option<CPUCache> {
+    for parent in caches.iter_mut() {
+        if parent.level > cache.level && is_subset(&cache.shared_cpus, &parent.shared_cpus) {
+            if let Some(cache) = insert_cache(&mut parent.children, cache) {
+                parent.children.push(cache);
+            }
+
+            return None;
+        }
+    }
+
+    Some(cache)
+}
+
+/// Get the CPU cache hierarchy by reading a folder in the format of `/sys/devices/system/cpu`.
+pub fn get_cpu_caches_from_path<P: AsRef<Path>>(
+    cpu_path: P,
+) -> Result<Vec<CPUCache>, ScannerError> {
+    let cpu_path = cpu_path.as_ref();
+
+    let online = match read_one_value(cpu_path.join("online"))? {
+        Some(online) => parse_cpu_list(online)?,
+        None => Vec::new(),
+    };
+
+    let mut flat_caches: Vec<CPUCache> = Vec::new();
+
+    for cpu in online {
+        let cache_path = cpu_path.join(format!("cpu{cpu}")).join("cache");
+
+        for index in 0.. {
+            let index_path = cache_path.join(format!("index{index}"));
+
+            let level = match read_one_value(index_path.join("level"))? {
+                Some(level) => level.parse()?,
+                None => break,
+            };
+
+            let cache_type = match read_one_value(index_path.join("type"))? {
+                Some(cache_type) => {
+                    CPUCacheType::from_str(cache_type).ok_or(ErrorKind::InvalidData)?
+                },
+                None => CPUCacheType::Unified,
+            };
+
+            let shared_cpus = match read_one_value(index_path.join("shared_cpu_list"))? {
+                Some(shared_cpu_list) => parse_cpu_list(shared_cpu_list)?,
+                None => vec![cpu],
+            };
+
+            if flat_caches.iter().any(|cache| {
+                cache.level == level
+                    && cache.cache_type == cache_type
+                    && cache.shared_cpus == shared_cpus
+            }) {
+                continue;
+            }
+
+            let size = match read_one_value(index_path.join("size"))? {
+                Some(size) => parse_size(&size)?,
+                None => 0,
+            };
+
+            let line_size = match read_one_value(index_path.join("coherency_line_size"))? {
+                Some(line_size) => Some(line_size.parse()?),
+                None => None,
+            };
+
+            let ways_of_associativity =
+                match read_one_value(index_path.join("ways_of_associativity"))? {
+                    Some(ways_of_associativity) => Some(ways_of_associativity.parse()?),
+                    None => None,
+                };
+
+            flat_caches.push(CPUCache {
+                level,
+                cache_type,
+                size,
+                line_size,
+                ways_of_associativity,
+                shared_cpus,
+                children: Vec::new(),
+            });
+        }
+    }
+
+    // higher levels first, so that parents are always inserted before their children
+    flat_caches.sort_by(|a, b| {
+        (Reverse(a.level), a.shared_cpus.first(), a.cache_type).cmp(&(
+            Reverse(b.level),
+            b.shared_cpus.first(),
+            b.cache_type,
+        ))
+    });
+
+    let mut caches = Vec::new();
+
+    for cache in flat_caches {
+        if let Some(cache) = insert_cache(&mut caches, cache) {
+            caches.push(cache);
+        }
+    }
+
+    Ok(caches)
+}
+
+/// Get the CPU cache hierarchy by reading the `/sys/devices/system/cpu/cpuN/cache/indexM` folders of all online CPUs. The caches are organized as trees grouped by the CPUs sharing them. Each root is a highest-level cache (usually L3 per package), and its children are lower-level caches shared by a subset of its CPUs.
+///
+/// ```rust
+/// use mprober_lib::cpu;
+///
+/// let cpu_caches = cpu::get_cpu_caches().unwrap();
+///
+/// println!("{cpu_caches:#?}");
+/// ```
+#[inline]
+pub fn get_cpu_caches() -> Result<Vec<CPUCache>, ScannerError> {
+    get_cpu_caches_from_path("/sys/devices/system/cpu")
+}
@@ -0,0 +1,235 @@
+use std::{collections::BTreeMap, io::ErrorKind, path::Path, thread::sleep, time::Duration};
+
+use crate::{
+    cpu::{get_all_cpus_stat, parse_cpu_list, CPUStat},
+    functions::read_one_value,
+    scanner_rust::ScannerError,
+};
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum CPUCoreClass {
+    /// Performance cores (P-cores, big cores). All cores of a non-hybrid CPU are in this class.
+    Performance,
+    /// Efficiency cores (E-cores, LITTLE cores).
+    Efficiency,
+    #[default]
+    Unknown,
+}
+
+impl CPUCoreClass {
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CPUCoreClass::Performance => "Performance",
+            CPUCoreClass::Efficiency => "Efficiency",
+            CPUCoreClass::Unknown => "Unknown",
+        }
+    }
+}
+
+/// Classify CPUs by a capability value such as `cpu_capacity` or `cpuinfo_max_freq`. Small differences (e.g. favored cores of Intel Turbo Boost Max 3.0) are not treated as hybrid.
+fn classify_by_value(values: &BTreeMap<usize, u64>) -> BTreeMap<usize, CPUCoreClass> {
+    let max = values.values().copied().max().unwrap_or(0);
+    let min = values.values().copied().min().unwrap_or(0);
+
+    let hybrid = (min as f64) < (max as f64) * 0.8;
+    let threshold = (min + max) / 2;
+
+    values
+        .iter()
+        .map(|(&cpu, &value)| {
+            let class = if !hybrid || value > threshold {
+                CPUCoreClass::Performance
+            } else {
+                CPUCoreClass::Efficiency
+            };
+
+            (cpu, class)
+        })
+        .collect()
+}
+
+fn classify_by_types(types_path: &Path) -> Result<BTreeMap<usize, CPUCoreClass>, ScannerError> {
+    let mut classes = BTreeMap::new();
+
+    let dir = match types_path.read_dir() {
+        Ok(dir) => dir,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(classes),
+        Err(err) => return Err(err.into()),
+    };
+
+    for dir_entry in dir {
+        let dir_entry = dir_entry?;
+
+        let type_name = dir_entry.file_name().to_string_lossy().to_lowercase();
+
+        let class = if type_name.contains("atom")
+            || type_name.contains("little")
+            || type_name.contains("efficiency")
+        {
+            CPUCoreClass::Efficiency
+        } else if type_name.contains("core")
+            || type_name.contains("big")
+            || type_name.contains("performance")
+        {
+            CPUCoreClass::Performance
+        } else {
+            continue;
+        };
+
+        if let Some(cpu_list) = read_one_value(dir_entry.path().join("cpulist"))? {
+            for cpu in parse_cpu_list(cpu_list)? {
+                classes.insert(cpu, class);
+            }
+        }
+    }
+
+    Ok(classes)
+}
+
+/// Detect the core class of every online CPU by reading a folder in the format of `/sys/devices`. The keys of the returned map are CPU numbers.
+pub fn get_cpu_core_classes_from_path<P: AsRef<Path>>(
+    devices_path: P,
+) -> Result<BTreeMap<usize, CPUCoreClass>, ScannerError> {
+    let devices_path = devices_path.as_ref();
+    let cpu_path = devices_path.join("system/cpu");
+
+    let online = match read_one_value(cpu_path.join("online"))? {
+        Some(online) => parse_cpu_list(online)?,
+        None => Vec::new(),
+    };
+
+    let mut classes = classify_by_types(&cpu_path.join("types"))?;
+
+    if classes.is_empty() {
+        for (pmu, class) in [
+            ("cpu_core/cpus", CPUCoreClass::Performance),
+            ("cpu_atom/cpus", CPUCoreClass::Efficiency),
+        ] {
+            if let Some(cpu_list) = read_one_value(devices_path.join(pmu))? {
+                for cpu in parse_cpu_list(cpu_list)? {
+                    classes.insert(cpu, class);
+                }
+            }
+        }
+    }
+
+    if classes.is_empty() {
+        for file in ["cpu_capacity", "cpufreq/cpuinfo_max_freq"] {
+            let mut values = BTreeMap::new();
+
+            for &cpu in online.iter() {
+                if let Some(value) = read_one_value(cpu_path.join(format!("cpu{cpu}")).join(file))?
+                {
+                    values.insert(cpu, value.parse()?);
+                }
+            }
+
+            // every online CPU needs the value to be comparable
+            if !values.is_empty() && values.len() == online.len() {
+                classes = classify_by_value(&values);
+
+                break;
+            }
+        }
+    }
+
+    Ok(online
+        .into_iter()
+        .map(|cpu| (cpu, classes.get(&cpu).copied().unwrap_or_default()))
+        .collect())
+}
+
+/// Detect the core class of every online CPU. The information comes from the `/sys/devices/system/cpu/types` folder, the `/sys/devices/cpu_core/cpus` and `/sys/devices/cpu_atom/cpus` files (Intel hybrid CPUs), the `/sys/devices/system/cpu/cpuN/cpu_capacity` files (ARM big.LITTLE) or the `/sys/devices/system/cpu/cpuN/cpufreq/cpuinfo_max_freq` files, in that order. The keys of the returned map are CPU numbers.
+///
+/// ```rust
+/// use mprober_lib::cpu;
+///
+/// let cpu_core_classes = cpu::get_cpu_core_classes().unwrap();
+///
+/// println!("{cpu_core_classes:#?}");
+/// ```
+#[inline]
+pub fn get_cpu_core_classes() -> Result<BTreeMap<usize, CPUCoreClass>, ScannerError> {
+    get_cpu_core_classes_from_path("/sys/devices")
+}
+
+/// Calculate CPU utilization in percentage of each core class within a specific time interval. It will cause the current thread to sleep. If the number it returns is `1.0`, means `100%`.
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use mprober_lib::cpu;
+///
+/// let utilization = cpu::get_cpu_utilization_by_core_class_in_percentage(
+///     Duration::from_millis(100),
+/// )
+/// .unwrap();
+///
+/// for (core_class, cpu_percentage) in utilization {
+///     println!("{}: {:.2}%", core_class.as_str(), cpu_percentage * 100.0);
+/// }
+/// ```
+pub fn get_cpu_utilization_by_core_class_in_percentage(
+    interval: Duration,
+) -> Result<Vec<(CPUCoreClass, f64)>, ScannerError> {
+    let classes = get_cpu_core_classes()?;
+
+    let sum_by_class = |cpus_stat: Vec<CPUStat>| {
+        let mut sum: BTreeMap<CPUCoreClass, CPUStat> = BTreeMap::new();
+
+        for cpu_stat in cpus_stat {
+            let class = cpu_stat
+                .cpu
+                .and_then(|cpu| classes.get(&cpu).copied())
+                .unwrap_or(CPUCoreClass::Unknown);
+
+            let class_stat = sum.entry(class).or_default();
+
+            class_stat.user += cpu_stat.user;
+            class_stat.nice += cpu_stat.nice;
+            class_stat.system += cpu_stat.system;
+            class_stat.idle += cpu_stat.idle;
+            class_stat.iowait += cpu_stat.iowait;
+            class_stat.irq += cpu_stat.irq;
+            class_stat.softirq += cpu_stat.softirq;
+            class_stat.steal += cpu_stat.steal;
+            class_stat.guest += cpu_stat.guest;
+            class_stat.guest_nice += cpu_stat.guest_nice;
+        }
+
+        sum
+    };
+
+    let pre_cpus_stat = get_all_cpus_stat(false)?;
+
+    sleep(interval);
+
+    let cpus_stat = get_all_cpus_stat(false)?;
+
+    // only compare CPUs which are online during the whole interval
+    let cpus_stat: Vec<CPUStat> = cpus_stat
+        .into_iter()
+        .filter(|cpu_stat| {
+            pre_cpus_stat.iter().any(|pre_cpu_stat| pre_cpu_stat.cpu == cpu_stat.cpu)
+        })
+        .collect();
+    let pre_cpus_stat: Vec<CPUStat> = pre_cpus_stat
+        .into_iter()
+        .filter(|pre_cpu_stat| cpus_stat.iter().any(|cpu_stat| pre_cpu_stat.cpu == cpu_stat.cpu))
+        .collect();
+
+    let pre_sum = sum_by_class(pre_cpus_stat);
+    let sum = sum_by_class(cpus_stat);
+
+    let result = sum
+        .into_iter()
+        .filter_map(|(class, class_stat)| {
+            pre_sum.get(&class).map(|pre_class_stat| {
+                (class, pre_class_stat.compute_cpu_utilization_in_percentage(&class_stat))
+            })
+        })
+        .collect();
+
+    Ok(result)
+}
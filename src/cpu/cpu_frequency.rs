@@ -0,0 +1,105 @@
+use std::path::Path;
+
+use crate::{cpu::parse_cpu_list, functions::read_one_value, scanner_rust::ScannerError};
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct CPUFrequency {
+    pub cpu:                           usize,
+    /// In kHz. (`scaling_cur_freq`)
+    pub current:                       Option<u64>,
+    /// In kHz. The lower limit set by the governor. (`scaling_min_freq`)
+    pub min:                           Option<u64>,
+    /// In kHz. The upper limit set by the governor. (`scaling_max_freq`)
+    pub max:                           Option<u64>,
+    /// In kHz. The lowest frequency the hardware supports. (`cpuinfo_min_freq`)
+    pub hardware_min:                  Option<u64>,
+    /// In kHz. The highest frequency the hardware supports, including boost. (`cpuinfo_max_freq`)
+    pub hardware_max:                  Option<u64>,
+    /// In kHz. Only provided by some drivers such as `intel_pstate`. (`base_frequency`)
+    pub base:                          Option<u64>,
+    pub governor:                      Option<String>,
+    pub available_governors:           Vec<String>,
+    pub driver:                        Option<String>,
+    pub energy_performance_preference: Option<String>,
+    /// Whether frequency boost (Turbo Boost, Turbo Core) is enabled. `None` if it is unknown.
+    pub boost:                         Option<bool>,
+}
+
+/// Values which can not be parsed, such as `<unknown>` provided by some drivers, are treated as `None`.
+#[inline]
+fn read_khz<P: AsRef<Path>>(path: P) -> Result<Option<u64>, ScannerError> {
+    Ok(read_one_value(path)?.and_then(|khz| khz.parse().ok()))
+}
+
+#[inline]
+fn read_boost<P: AsRef<Path>>(path: P) -> Result<Option<bool>, ScannerError> {
+    Ok(read_one_value(path)?.map(|boost| boost == "1"))
+}
+
+/// Get frequency scaling information of all online CPUs by reading a folder in the format of `/sys/devices/system/cpu`.
+pub fn get_cpus_frequency_from_path<P: AsRef<Path>>(
+    cpu_path: P,
+) -> Result<Vec<CPUFrequency>, ScannerError> {
+    let cpu_path = cpu_path.as_ref();
+
+    let online = match read_one_value(cpu_path.join("online"))? {
+        Some(online) => parse_cpu_list(online)?,
+        None => Vec::new(),
+    };
+
+    // the global boost switch of `acpi-cpufreq` and `amd-pstate`, or the turbo switch of `intel_pstate`
+    let global_boost = match read_boost(cpu_path.join("cpufreq/boost"))? {
+        Some(boost) => Some(boost),
+        None => read_boost(cpu_path.join("intel_pstate/no_turbo"))?.map(|no_turbo| !no_turbo),
+    };
+
+    let mut cpus_frequency = Vec::with_capacity(online.len());
+
+    for cpu in online {
+        let cpufreq_path = cpu_path.join(format!("cpu{cpu}")).join("cpufreq");
+
+        let available_governors = read_one_value(cpufreq_path.join("scaling_available_governors"))?
+            .map(|governors| governors.split_ascii_whitespace().map(String::from).collect())
+            .unwrap_or_default();
+
+        let boost = match read_boost(cpufreq_path.join("boost"))? {
+            Some(boost) => Some(boost),
+            None => global_boost,
+        };
+
+        cpus_frequency.push(CPUFrequency {
+            cpu,
+            current: read_khz(cpufreq_path.join("scaling_cur_freq"))?,
+            min: read_khz(cpufreq_path.join("scaling_min_freq"))?,
+            max: read_khz(cpufreq_path.join("scaling_max_freq"))?,
+            hardware_min: read_khz(cpufreq_path.join("cpuinfo_min_freq"))?,
+            hardware_max: read_khz(cpufreq_path.join("cpuinfo_max_freq"))?,
+            base: read_khz(cpufreq_path.join("base_frequency"))?,
+            governor: read_one_value(cpufreq_path.join("scaling_governor"))?,
+            available_governors,
+            driver: read_one_value(cpufreq_path.join("scaling_driver"))?,
+            energy_performance_preference: read_one_value(
+                cpufreq_path.join("energy_performance_preference"),
+            )?,
+            boost,
+        });
+    }
+
+    Ok(cpus_frequency)
+}
+
+/// Get frequency scaling information of all online CPUs by reading the `/sys/devices/system/cpu/cpuN/cpufreq` folders. If the kernel does not support frequency scaling (e.g. in many virtual machines), or a value can not be read as a number, the fields are `None`.
+///
+/// ```rust
+/// use mprober_lib::cpu;
+///
+/// let cpus_frequency = cpu::get_cpus_frequency().unwrap();
+///
+/// for cpu_frequency in cpus_frequency {
+///     println!("cpu{}: {:?}", cpu_frequency.cpu, cpu_frequency.governor);
+/// }
+/// ```
+#[inline]
+pub fn get_cpus_frequency() -> Result<Vec<CPUFrequency>, ScannerError> {
+    get_cpus_frequency_from_path("/sys/devices/system/cpu")
+}
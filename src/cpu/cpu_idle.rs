@@ -0,0 +1,197 @@
+use std::{io::ErrorKind, path::Path, thread::sleep, time::Duration};
+
+use crate::{cpu::parse_cpu_list, functions::read_one_value, scanner_rust::ScannerError};
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct CPUIdleState {
+    /// The `M` of the `stateM` folder.
+    pub state:       usize,
+    /// Such as `POLL`, `C1` or `C6`.
+    pub name:        String,
+    /// (`desc`)
+    pub description: String,
+    /// The exit latency, in microseconds.
+    pub latency:     u64,
+    /// The target residency, in microseconds. Entering this state is only worthwhile if the CPU stays idle for at least this long.
+    pub residency:   u64,
+    /// The number of times this state was entered.
+    pub usage:       u64,
+    /// The total time spent in this state, in microseconds.
+    pub time:        u64,
+    /// (`disable`)
+    pub disabled:    bool,
+}
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct CPUIdle {
+    pub cpu:    usize,
+    pub states: Vec<CPUIdleState>,
+}
+
+impl CPUIdle {
+    /// Compute the residency of each idle state in percentage between two `CPUIdle` instances at different time. The returned vector is aligned with `states`. If a number is `1.0`, means `100%`.
+    ///
+    /// ```rust
+    /// use std::{thread::sleep, time::Duration};
+    ///
+    /// use mprober_lib::cpu;
+    ///
+    /// let pre_cpus_idle = cpu::get_cpus_idle().unwrap();
+    ///
+    /// let interval = Duration::from_millis(100);
+    ///
+    /// sleep(interval);
+    ///
+    /// let cpus_idle = cpu::get_cpus_idle().unwrap();
+    ///
+    /// if !pre_cpus_idle.is_empty() && !cpus_idle.is_empty() {
+    ///     let residency = pre_cpus_idle[0]
+    ///         .compute_residency_in_percentage(&cpus_idle[0], interval);
+    ///
+    ///     println!("{residency:?}");
+    /// }
+    /// ```
+    #[inline]
+    pub fn compute_residency_in_percentage(
+        &self,
+        cpu_idle_after_this: &CPUIdle,
+        interval: Duration,
+    ) -> Vec<f64> {
+        let micros = interval.as_micros() as f64;
+
+        cpu_idle_after_this
+            .states
+            .iter()
+            .map(|state| {
+                match self.states.iter().find(|pre_state| pre_state.state == state.state) {
+                    Some(pre_state) => {
+                        (state.time.saturating_sub(pre_state.time) as f64 / micros).min(1.0)
+                    },
+                    None => 0.0,
+                }
+            })
+            .collect()
+    }
+}
+
+#[inline]
+fn read_u64<P: AsRef<Path>>(path: P) -> Result<u64, ScannerError> {
+    Ok(read_one_value(path)?.ok_or(ErrorKind::UnexpectedEof)?.parse()?)
+}
+
+/// Get idle states of all online CPUs by reading a folder in the format of `/sys/devices/system/cpu`.
+pub fn get_cpus_idle_from_path<P: AsRef<Path>>(
+    sys_cpu_path: P,
+) -> Result<Vec<CPUIdle>, ScannerError> {
+    let cpu_path = sys_cpu_path.as_ref();
+
+    let online = match read_one_value(cpu_path.join("online"))? {
+        Some(online) => parse_cpu_list(online)?,
+        None => Vec::new(),
+    };
+
+    let mut cpus_idle = Vec::with_capacity(online.len());
+
+    for cpu in online {
+        let cpuidle_path = cpu_path.join(format!("cpu{cpu}")).join("cpuidle");
+
+        let mut states = Vec::new();
+
+        for state in 0.. {
+            let state_path = cpuidle_path.join(format!("state{state}"));
+
+            let name = match read_one_value(state_path.join("name"))? {
+                Some(name) => name,
+                None => break,
+            };
+
+            states.push(CPUIdleState {
+                state,
+                name,
+                description: read_one_value(state_path.join("desc"))?.unwrap_or_default(),
+                latency: read_u64(state_path.join("latency"))?,
+                residency: read_u64(state_path.join("residency"))?,
+                usage: read_u64(state_path.join("usage"))?,
+                time: read_u64(state_path.join("time"))?,
+                disabled: read_one_value(state_path.join("disable"))?.as_deref() == Some("1"),
+            });
+        }
+
+        cpus_idle.push(CPUIdle {
+            cpu,
+            states,
+        });
+    }
+
+    Ok(cpus_idle)
+}
+
+/// Get idle states (C-states) of all online CPUs by reading the `/sys/devices/system/cpu/cpuN/cpuidle/stateM` folders. If the kernel does not use a cpuidle driver (e.g. in many virtual machines), the `states` are empty.
+///
+/// ```rust
+/// use mprober_lib::cpu;
+///
+/// let cpus_idle = cpu::get_cpus_idle().unwrap();
+///
+/// for cpu_idle in cpus_idle {
+///     for state in cpu_idle.states {
+///         println!(
+///             "cpu{}: {} {} {}",
+///             cpu_idle.cpu, state.name, state.usage, state.time
+///         );
+///     }
+/// }
+/// ```
+#[inline]
+pub fn get_cpus_idle() -> Result<Vec<CPUIdle>, ScannerError> {
+    get_cpus_idle_from_path("/sys/devices/system/cpu")
+}
+
+/// Calculate the residency of each idle state of all CPUs in percentage within a specific time interval. It will cause the current thread to sleep. If a number is `1.0`, means `100%`. CPUs which are not online during the whole interval are not included.
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use mprober_lib::cpu;
+///
+/// let all_cpu_idle_residency = cpu::get_all_cpu_idle_residency_in_percentage(
+///     Duration::from_millis(100),
+/// )
+/// .unwrap();
+///
+/// for (cpu_idle, residency) in all_cpu_idle_residency {
+///     for (state, percentage) in cpu_idle.states.iter().zip(residency) {
+///         println!(
+///             "cpu{}: {} {:.2}%",
+///             cpu_idle.cpu,
+///             state.name,
+///             percentage * 100.0
+///         );
+///     }
+/// }
+/// ```
+pub fn get_all_cpu_idle_residency_in_percentage(
+    interval: Duration,
+) -> Result<Vec<(CPUIdle, Vec<f64>)>, ScannerError> {
+    let pre_cpus_idle = get_cpus_idle()?;
+
+    sleep(interval);
+
+    let cpus_idle = get_cpus_idle()?;
+
+    let result = cpus_idle
+        .into_iter()
+        .filter_map(|cpu_idle| {
+            pre_cpus_idle.iter().find(|pre_cpu_idle| pre_cpu_idle.cpu == cpu_idle.cpu).map(
+                |pre_cpu_idle| {
+                    let residency =
+                        pre_cpu_idle.compute_residency_in_percentage(&cpu_idle, interval);
+
+                    (cpu_idle, residency)
+                },
+            )
+        })
+        .collect();
+
+    Ok(result)
+}
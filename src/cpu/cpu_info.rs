@@ -1,17 +1,19 @@
 use std::{
     collections::BTreeSet,
     hash::{Hash, Hasher},
-    io::ErrorKind,
-    str::from_utf8_unchecked,
+    path::Path,
 };
 
-use crate::scanner_rust::{generic_array::typenum::U1024, ScannerAscii, ScannerError};
+use crate::{
+    cpu::get_logical_cpus_from_path, functions::read_one_value, scanner_rust::ScannerError,
+};
 
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Default, Debug, Clone)]
 pub struct CPU {
     pub physical_id: usize,
     pub model_name:  String,
+    /// The current frequency of each logical CPU. It may be empty if the frequency is not available, such as on some ARM boards.
     pub cpus_mhz:    Vec<f64>,
     pub siblings:    usize,
     pub cpu_cores:   usize,
@@ -31,129 +33,109 @@ impl PartialEq for CPU {
     }
 }
 
-/// Get CPU information by reading the `/proc/cpuinfo` file.
+/// Get CPU information by reading a file in the format of `/proc/cpuinfo` and a folder in the format of `/sys/devices/system/cpu`. Items which are missing in the cpuinfo file (for example, on ARM and RISC-V) are filled from the topology and cpufreq information in the sysfs folder.
 ///
 /// ```rust
 /// use mprober_lib::cpu;
 ///
-/// let cpus = cpu::get_cpus().unwrap();
+/// let cpus =
+///     cpu::get_cpus_from_paths("/proc/cpuinfo", "/sys/devices/system/cpu")
+///         .unwrap();
 ///
 /// println!("{cpus:#?}");
 /// ```
-pub fn get_cpus() -> Result<Vec<CPU>, ScannerError> {
-    const USEFUL_ITEMS: [&[u8]; 5] =
-        [b"model name", b"cpu MHz", b"physical id", b"siblings", b"cpu cores"];
-    const MODEL_NAME_INDEX: usize = 0;
-    const CPU_MHZ_INDEX: usize = 1;
-    const PHYSICAL_ID_INDEX: usize = 2;
-    const SIBLINGS_INDEX: usize = 3;
-    const CPU_CORES: usize = 4;
-
-    let mut sc: ScannerAscii<_, U1024> = ScannerAscii::scan_path2("/proc/cpuinfo")?;
-
-    let mut cpus = Vec::with_capacity(1);
-    let mut physical_ids: BTreeSet<usize> = BTreeSet::new();
-
-    let mut physical_id = 0;
-    let mut model_name = String::new();
-    let mut cpus_mhz = Vec::with_capacity(1);
-    let mut siblings = 0;
-    let mut cpu_cores = 0;
-
-    'outer: loop {
-        'item: for (i, &item) in USEFUL_ITEMS.iter().enumerate() {
-            let item_len = item.len();
-
-            loop {
-                match sc.next_line_raw()? {
-                    Some(line) => {
-                        if line.starts_with(item) {
-                            let colon_index = line[item_len..]
-                                .iter()
-                                .copied()
-                                .position(|b| b == b':')
-                                .ok_or(ErrorKind::InvalidData)?;
-
-                            let value = unsafe {
-                                from_utf8_unchecked(&line[(item_len + colon_index + 1)..])
-                            }
-                            .trim();
-
-                            match i {
-                                MODEL_NAME_INDEX => {
-                                    if model_name.is_empty() {
-                                        model_name.push_str(value);
-                                    }
-                                },
-                                CPU_MHZ_INDEX => {
-                                    cpus_mhz.push(value.parse()?);
-                                },
-                                PHYSICAL_ID_INDEX => {
-                                    physical_id = value.parse()?;
-
-                                    if physical_ids.contains(&physical_id) {
-                                        break 'item;
-                                    }
-                                },
-                                SIBLINGS_INDEX => {
-                                    siblings = value.parse()?;
-                                },
-                                CPU_CORES => {
-                                    cpu_cores = value.parse()?;
-
-                                    break 'item;
-                                },
-                                _ => unreachable!(),
-                            }
-
-                            break;
-                        }
-                    },
-                    None => {
-                        if i == MODEL_NAME_INDEX {
-                            break 'outer;
-                        } else {
-                            return Err(ErrorKind::UnexpectedEof.into());
-                        }
-                    },
+pub fn get_cpus_from_paths<P: AsRef<Path>, S: AsRef<Path>>(
+    cpuinfo_path: P,
+    sys_cpu_path: S,
+) -> Result<Vec<CPU>, ScannerError> {
+    let sys_cpu_path = sys_cpu_path.as_ref();
+
+    let logical_cpus = get_logical_cpus_from_path(cpuinfo_path)?;
+
+    let mut cpus: Vec<CPU> = Vec::with_capacity(1);
+    let mut cpus_logical_count: Vec<usize> = Vec::with_capacity(1);
+    let mut cpus_core_ids: Vec<BTreeSet<usize>> = Vec::with_capacity(1);
+
+    for logical_cpu in logical_cpus {
+        let cpu_path = sys_cpu_path.join(format!("cpu{}", logical_cpu.processor));
+
+        let physical_id = match logical_cpu.physical_id {
+            Some(physical_id) => physical_id,
+            None => {
+                // it can be -1 if the firmware does not provide it
+                match read_one_value(cpu_path.join("topology/physical_package_id"))? {
+                    Some(physical_id) => physical_id.parse::<i64>()?.max(0) as usize,
+                    None => 0,
                 }
-            }
+            },
+        };
+
+        let core_id = match logical_cpu.core_id {
+            Some(core_id) => core_id,
+            None => match read_one_value(cpu_path.join("topology/core_id"))? {
+                Some(core_id) => core_id.parse::<i64>()?.max(0) as usize,
+                None => logical_cpu.processor,
+            },
+        };
+
+        let cpu_mhz = match logical_cpu.cpu_mhz {
+            Some(cpu_mhz) => Some(cpu_mhz),
+            // some cpufreq drivers report `<unknown>`
+            None => read_one_value(cpu_path.join("cpufreq/scaling_cur_freq"))?
+                .and_then(|cpu_khz| cpu_khz.parse::<f64>().ok())
+                .map(|cpu_khz| cpu_khz / 1000.0),
+        };
+
+        let index = match cpus.iter().position(|cpu| cpu.physical_id == physical_id) {
+            Some(index) => index,
+            None => {
+                cpus.push(CPU {
+                    physical_id,
+                    model_name: logical_cpu.get_model_name().unwrap_or_default(),
+                    cpus_mhz: Vec::with_capacity(1),
+                    siblings: logical_cpu.siblings.unwrap_or(0),
+                    cpu_cores: logical_cpu.cpu_cores.unwrap_or(0),
+                });
+                cpus_logical_count.push(0);
+                cpus_core_ids.push(BTreeSet::new());
+
+                cpus.len() - 1
+            },
+        };
+
+        if let Some(cpu_mhz) = cpu_mhz {
+            cpus[index].cpus_mhz.push(cpu_mhz);
         }
 
-        if siblings == cpus_mhz.len() {
-            let cpu = CPU {
-                physical_id,
-                model_name,
-                cpus_mhz,
-                siblings,
-                cpu_cores,
-            };
-
-            cpus.push(cpu);
-            physical_ids.insert(physical_id);
-
-            physical_id = 0;
-            model_name = String::new();
-            cpus_mhz = Vec::with_capacity(1);
-            siblings = 0;
-            cpu_cores = 0;
+        cpus_logical_count[index] += 1;
+        cpus_core_ids[index].insert(core_id);
+    }
+
+    for ((cpu, logical_count), core_ids) in
+        cpus.iter_mut().zip(cpus_logical_count).zip(cpus_core_ids)
+    {
+        if cpu.siblings == 0 {
+            cpu.siblings = logical_count;
         }
 
-        loop {
-            let line_length = sc.drop_next_line()?;
-
-            match line_length {
-                Some(line_length) => {
-                    if line_length == 0 {
-                        break;
-                    }
-                },
-                None => {
-                    break 'outer;
-                },
-            }
+        if cpu.cpu_cores == 0 {
+            cpu.cpu_cores = core_ids.len();
         }
     }
 
     Ok(cpus)
 }
+
+/// Get CPU information by reading the `/proc/cpuinfo` file and the `/sys/devices/system/cpu` folder.
+///
+/// ```rust
+/// use mprober_lib::cpu;
+///
+/// let cpus = cpu::get_cpus().unwrap();
+///
+/// println!("{cpus:#?}");
+/// ```
+#[inline]
+pub fn get_cpus() -> Result<Vec<CPU>, ScannerError> {
+    get_cpus_from_paths("/proc/cpuinfo", "/sys/devices/system/cpu")
+}
@@ -0,0 +1,176 @@
+use std::{io::ErrorKind, path::Path, str::from_utf8_unchecked, thread::sleep, time::Duration};
+
+use crate::scanner_rust::{generic_array::typenum::U1024, ScannerAscii, ScannerError};
+
+#[derive(Default, Debug, Clone)]
+pub struct SchedLatency {
+    /// The average time spent waiting on a run queue before each timeslice.
+    pub average_wait: Duration,
+    /// The time spent waiting on a run queue divided by the time interval. It can exceed `1.0` if more than one task is waiting at the same time.
+    pub wait_rate:    f64,
+    /// The number of timeslices within the time interval.
+    pub timeslices:   u64,
+}
+
+impl SchedLatency {
+    /// Compute the run-queue latency from the increments of the waiting time (in nanoseconds) and the timeslices within a time interval.
+    #[inline]
+    pub fn from_increments(
+        d_wait_time: u64,
+        d_timeslices: u64,
+        interval: Duration,
+    ) -> SchedLatency {
+        let average_wait = match d_wait_time.checked_div(d_timeslices) {
+            Some(average_wait) => Duration::from_nanos(average_wait),
+            None => Duration::ZERO,
+        };
+
+        let wait_rate = d_wait_time as f64 / interval.as_nanos() as f64;
+
+        SchedLatency {
+            average_wait,
+            wait_rate,
+            timeslices: d_timeslices,
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct CPUSchedStat {
+    pub cpu:                 usize,
+    /// The number of times `sched_yield()` was called. (`yld_count`)
+    pub yield_count:         u64,
+    /// The number of times `schedule()` was called. (`sched_count`)
+    pub schedule_count:      u64,
+    /// The number of times `schedule()` left the CPU idle. (`sched_goidle`)
+    pub schedule_idle_count: u64,
+    /// The number of times `try_to_wake_up()` was called. (`ttwu_count`)
+    pub wakeup_count:        u64,
+    /// The number of times `try_to_wake_up()` woke up a task on the local CPU. (`ttwu_local`)
+    pub wakeup_local_count:  u64,
+    /// The total time spent running by tasks on this CPU, in nanoseconds.
+    pub run_time:            u64,
+    /// The total time spent waiting on the run queue by tasks on this CPU, in nanoseconds.
+    pub wait_time:           u64,
+    /// The number of timeslices run on this CPU.
+    pub timeslices:          u64,
+}
+
+impl CPUSchedStat {
+    /// Compute the run-queue latency between two `CPUSchedStat` instances at different time.
+    #[inline]
+    pub fn compute_sched_latency(
+        &self,
+        cpu_sched_stat_after_this: &CPUSchedStat,
+        interval: Duration,
+    ) -> SchedLatency {
+        SchedLatency::from_increments(
+            cpu_sched_stat_after_this.wait_time.saturating_sub(self.wait_time),
+            cpu_sched_stat_after_this.timeslices.saturating_sub(self.timeslices),
+            interval,
+        )
+    }
+}
+
+/// Get scheduler statistics of all online CPUs by reading a file in the format of `/proc/schedstat` (version 15 and later). The `domain` lines are skipped.
+pub fn get_cpus_sched_stat_from_path<P: AsRef<Path>>(
+    path: P,
+) -> Result<Vec<CPUSchedStat>, ScannerError> {
+    let mut sc: ScannerAscii<_, U1024> = ScannerAscii::scan_path2(path)?;
+
+    let mut cpus_sched_stat = Vec::new();
+
+    while let Some(label) = sc.next_raw()? {
+        if let Some(cpu) = label.strip_prefix(b"cpu") {
+            let cpu = unsafe { from_utf8_unchecked(cpu) }.parse()?;
+
+            let yield_count = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+
+            // legacy, always 0
+            sc.drop_next()?.ok_or(ErrorKind::UnexpectedEof)?;
+
+            let schedule_count = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+            let schedule_idle_count = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+            let wakeup_count = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+            let wakeup_local_count = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+            let run_time = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+            let wait_time = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+            let timeslices = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+
+            cpus_sched_stat.push(CPUSchedStat {
+                cpu,
+                yield_count,
+                schedule_count,
+                schedule_idle_count,
+                wakeup_count,
+                wakeup_local_count,
+                run_time,
+                wait_time,
+                timeslices,
+            });
+        }
+
+        sc.drop_next_line()?;
+    }
+
+    Ok(cpus_sched_stat)
+}
+
+/// Get scheduler statistics of all online CPUs by reading the `/proc/schedstat` file. The kernel needs to be built with `CONFIG_SCHEDSTATS`, or a `NotFound` error is returned.
+///
+/// ```rust
+/// use mprober_lib::cpu;
+///
+/// if let Ok(cpus_sched_stat) = cpu::get_cpus_sched_stat() {
+///     println!("{cpus_sched_stat:#?}");
+/// }
+/// ```
+#[inline]
+pub fn get_cpus_sched_stat() -> Result<Vec<CPUSchedStat>, ScannerError> {
+    get_cpus_sched_stat_from_path("/proc/schedstat")
+}
+
+/// Calculate the run-queue latency of all CPUs within a specific time interval by reading the `/proc/schedstat` file. It will cause the current thread to sleep. CPUs which are not online during the whole interval are not included.
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use mprober_lib::cpu;
+///
+/// if let Ok(all_cpu_sched_latency) =
+///     cpu::get_all_cpu_sched_latency(Duration::from_millis(100))
+/// {
+///     for (cpu_sched_stat, sched_latency) in all_cpu_sched_latency {
+///         println!(
+///             "cpu{}: {:?}",
+///             cpu_sched_stat.cpu, sched_latency.average_wait
+///         );
+///     }
+/// }
+/// ```
+pub fn get_all_cpu_sched_latency(
+    interval: Duration,
+) -> Result<Vec<(CPUSchedStat, SchedLatency)>, ScannerError> {
+    let pre_cpus_sched_stat = get_cpus_sched_stat()?;
+
+    sleep(interval);
+
+    let cpus_sched_stat = get_cpus_sched_stat()?;
+
+    let result = cpus_sched_stat
+        .into_iter()
+        .filter_map(|cpu_sched_stat| {
+            pre_cpus_sched_stat
+                .iter()
+                .find(|pre_cpu_sched_stat| pre_cpu_sched_stat.cpu == cpu_sched_stat.cpu)
+                .map(|pre_cpu_sched_stat| {
+                    let sched_latency =
+                        pre_cpu_sched_stat.compute_sched_latency(&cpu_sched_stat, interval);
+
+                    (cpu_sched_stat, sched_latency)
+                })
+        })
+        .collect();
+
+    Ok(result)
+}
@@ -1,7 +1,7 @@
-use std::{io::ErrorKind, thread::sleep, time::Duration};
+use std::{io::ErrorKind, path::Path, str::from_utf8_unchecked, thread::sleep, time::Duration};
 
 use crate::{
-    cpu::CPUTime,
+    cpu::{CPUTime, CPUTimeBreakdown},
     scanner_rust::{
         generic_array::typenum::{U1024, U72},
         ScannerAscii, ScannerError,
@@ -10,6 +10,8 @@ use crate::{
 
 #[derive(Default, Debug, Clone)]
 pub struct CPUStat {
+    /// The CPU number in the `cpuN` label. `None` for the aggregate of all CPUs (the `cpu` label).
+    pub cpu:        Option<usize>,
     pub user:       u64,
     pub nice:       u64,
     pub system:     u64,
@@ -73,6 +75,76 @@ impl CPUStat {
 
         d_non_idle as f64 / d_total as f64
     }
+
+    /// Compute the fractions of time spent in each CPU state between two `CPUStat` instances at different time, like the `mpstat` command. The kernel already counts `guest` and `guest_nice` in `user` and `nice`, so they are subtracted from `user` and `nice` respectively.
+    ///
+    /// ```rust
+    /// use std::{thread::sleep, time::Duration};
+    ///
+    /// use mprober_lib::cpu;
+    ///
+    /// let pre_average_cpu_stat = cpu::get_average_cpu_stat().unwrap();
+    ///
+    /// sleep(Duration::from_millis(100));
+    ///
+    /// let average_cpu_stat = cpu::get_average_cpu_stat().unwrap();
+    ///
+    /// let breakdown = pre_average_cpu_stat.compute_breakdown(&average_cpu_stat);
+    ///
+    /// println!("user: {:.2}%", breakdown.user * 100.0);
+    /// println!("system: {:.2}%", breakdown.system * 100.0);
+    /// println!("iowait: {:.2}%", breakdown.iowait * 100.0);
+    /// println!("steal: {:.2}%", breakdown.steal * 100.0);
+    /// ```
+    pub fn compute_breakdown(&self, cpu_stat_after_this: &CPUStat) -> CPUTimeBreakdown {
+        let after = cpu_stat_after_this;
+
+        // counters can go backwards when a CPU is hotplugged
+        let d_guest = after.guest.saturating_sub(self.guest);
+        let d_guest_nice = after.guest_nice.saturating_sub(self.guest_nice);
+        let d_user = after.user.saturating_sub(self.user).saturating_sub(d_guest);
+        let d_nice = after.nice.saturating_sub(self.nice).saturating_sub(d_guest_nice);
+        let d_system = after.system.saturating_sub(self.system);
+        let d_idle = after.idle.saturating_sub(self.idle);
+        let d_iowait = after.iowait.saturating_sub(self.iowait);
+        let d_irq = after.irq.saturating_sub(self.irq);
+        let d_softirq = after.softirq.saturating_sub(self.softirq);
+        let d_steal = after.steal.saturating_sub(self.steal);
+
+        let d_total = d_user
+            + d_nice
+            + d_system
+            + d_idle
+            + d_iowait
+            + d_irq
+            + d_softirq
+            + d_steal
+            + d_guest
+            + d_guest_nice;
+
+        if d_total == 0 {
+            return CPUTimeBreakdown {
+                cpu: after.cpu,
+                ..CPUTimeBreakdown::default()
+            };
+        }
+
+        let d_total_f64 = d_total as f64;
+
+        CPUTimeBreakdown {
+            cpu:        after.cpu,
+            user:       d_user as f64 / d_total_f64,
+            nice:       d_nice as f64 / d_total_f64,
+            system:     d_system as f64 / d_total_f64,
+            idle:       d_idle as f64 / d_total_f64,
+            iowait:     d_iowait as f64 / d_total_f64,
+            irq:        d_irq as f64 / d_total_f64,
+            softirq:    d_softirq as f64 / d_total_f64,
+            steal:      d_steal as f64 / d_total_f64,
+            guest:      d_guest as f64 / d_total_f64,
+            guest_nice: d_guest_nice as f64 / d_total_f64,
+        }
+    }
 }
 
 /// Get average CPU stats by reading the `/proc/stat` file.
@@ -102,6 +174,7 @@ pub fn get_average_cpu_stat() -> Result<CPUStat, ScannerError> {
         let guest_nice = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
 
         Ok(CPUStat {
+            cpu: None,
             user,
             nice,
             system,
@@ -118,17 +191,12 @@ pub fn get_average_cpu_stat() -> Result<CPUStat, ScannerError> {
     }
 }
 
-/// Get all CPUs' stats with or without the average by reading the `/proc/stat` file.
-///
-/// ```rust
-/// use mprober_lib::cpu;
-///
-/// let all_cpus_stat = cpu::get_all_cpus_stat(false).unwrap();
-///
-/// println!("{all_cpus_stat:#?}");
-/// ```
-pub fn get_all_cpus_stat(with_average: bool) -> Result<Vec<CPUStat>, ScannerError> {
-    let mut sc: ScannerAscii<_, U1024> = ScannerAscii::scan_path2("/proc/stat")?;
+/// Get all CPUs' stats with or without the average by reading a file in the format of `/proc/stat`. Each CPU is labelled with the number in its `cpuN` line, so offline CPUs are skipped rather than shifting the numbers.
+pub fn get_all_cpus_stat_from_path<P: AsRef<Path>>(
+    path: P,
+    with_average: bool,
+) -> Result<Vec<CPUStat>, ScannerError> {
+    let mut sc: ScannerAscii<_, U1024> = ScannerAscii::scan_path2(path)?;
 
     let mut cpus_stat = Vec::with_capacity(1);
 
@@ -148,6 +216,7 @@ pub fn get_all_cpus_stat(with_average: bool) -> Result<Vec<CPUStat>, ScannerErro
             let guest_nice = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
 
             let cpu_stat = CPUStat {
+                cpu: None,
                 user,
                 nice,
                 system,
@@ -171,7 +240,9 @@ pub fn get_all_cpus_stat(with_average: bool) -> Result<Vec<CPUStat>, ScannerErro
     loop {
         let label = sc.next_raw()?.ok_or(ErrorKind::UnexpectedEof)?;
 
-        if label.starts_with(b"cpu") {
+        if let Some(cpu) = label.strip_prefix(b"cpu") {
+            let cpu = Some(unsafe { from_utf8_unchecked(cpu) }.parse()?);
+
             let user = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
             let nice = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
             let system = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
@@ -184,6 +255,7 @@ pub fn get_all_cpus_stat(with_average: bool) -> Result<Vec<CPUStat>, ScannerErro
             let guest_nice = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
 
             let cpu_stat = CPUStat {
+                cpu,
                 user,
                 nice,
                 system,
@@ -205,6 +277,20 @@ pub fn get_all_cpus_stat(with_average: bool) -> Result<Vec<CPUStat>, ScannerErro
     Ok(cpus_stat)
 }
 
+/// Get all CPUs' stats with or without the average by reading the `/proc/stat` file.
+///
+/// ```rust
+/// use mprober_lib::cpu;
+///
+/// let all_cpus_stat = cpu::get_all_cpus_stat(false).unwrap();
+///
+/// println!("{all_cpus_stat:#?}");
+/// ```
+#[inline]
+pub fn get_all_cpus_stat(with_average: bool) -> Result<Vec<CPUStat>, ScannerError> {
+    get_all_cpus_stat_from_path("/proc/stat", with_average)
+}
+
 /// Calculate average CPU utilization in percentage within a specific time interval. It will cause the current thread to sleep. If the number it returns is `1.0`, means `100%`.
 ///
 /// ```rust
@@ -230,7 +316,7 @@ pub fn get_average_cpu_utilization_in_percentage(interval: Duration) -> Result<f
     Ok(pre_cpu_stat.compute_cpu_utilization_in_percentage(&cpu_stat))
 }
 
-/// Calculate all CPU utilization in percentage with or without the average within a specific time interval. It will cause the current thread to sleep. If the number it returns is `1.0`, means `100%`.
+/// Calculate all CPU utilization in percentage with or without the average within a specific time interval. It will cause the current thread to sleep. If the number it returns is `1.0`, means `100%`. CPUs which are not online during the whole interval are not included.
 ///
 /// ```rust
 /// use std::time::Duration;
@@ -260,11 +346,83 @@ pub fn get_all_cpu_utilization_in_percentage(
 
     let cpus_stat = get_all_cpus_stat(with_average)?;
 
-    let result = pre_cpus_stat
+    let result = cpus_stat
+        .into_iter()
+        .filter_map(|cpu_stat| {
+            pre_cpus_stat
+                .iter()
+                .find(|pre_cpu_stat| pre_cpu_stat.cpu == cpu_stat.cpu)
+                .map(|pre_cpu_stat| pre_cpu_stat.compute_cpu_utilization_in_percentage(&cpu_stat))
+        })
+        .collect();
+
+    Ok(result)
+}
+
+/// Calculate the average fractions of time spent in each CPU state within a specific time interval. It will cause the current thread to sleep.
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use mprober_lib::cpu;
+///
+/// let breakdown =
+///     cpu::get_average_cpu_time_breakdown(Duration::from_millis(100))
+///         .unwrap();
+///
+/// println!("{breakdown:#?}");
+/// ```
+#[inline]
+pub fn get_average_cpu_time_breakdown(
+    interval: Duration,
+) -> Result<CPUTimeBreakdown, ScannerError> {
+    let pre_cpu_stat = get_average_cpu_stat()?;
+
+    sleep(interval);
+
+    let cpu_stat = get_average_cpu_stat()?;
+
+    Ok(pre_cpu_stat.compute_breakdown(&cpu_stat))
+}
+
+/// Calculate the fractions of time spent in each CPU state of all CPUs with or without the average within a specific time interval. It will cause the current thread to sleep. CPUs which are not online during the whole interval are not included.
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use mprober_lib::cpu;
+///
+/// let breakdowns =
+///     cpu::get_all_cpu_time_breakdown(false, Duration::from_millis(100))
+///         .unwrap();
+///
+/// for breakdown in breakdowns {
+///     println!(
+///         "cpu{}: user {:.2}%, system {:.2}%",
+///         breakdown.cpu.unwrap(),
+///         breakdown.user * 100.0,
+///         breakdown.system * 100.0
+///     );
+/// }
+/// ```
+#[inline]
+pub fn get_all_cpu_time_breakdown(
+    with_average: bool,
+    interval: Duration,
+) -> Result<Vec<CPUTimeBreakdown>, ScannerError> {
+    let pre_cpus_stat = get_all_cpus_stat(with_average)?;
+
+    sleep(interval);
+
+    let cpus_stat = get_all_cpus_stat(with_average)?;
+
+    let result = cpus_stat
         .into_iter()
-        .zip(cpus_stat)
-        .map(|(pre_cpus_stat, cpus_stat)| {
-            pre_cpus_stat.compute_cpu_utilization_in_percentage(&cpus_stat)
+        .filter_map(|cpu_stat| {
+            pre_cpus_stat
+                .iter()
+                .find(|pre_cpu_stat| pre_cpu_stat.cpu == cpu_stat.cpu)
+                .map(|pre_cpu_stat| pre_cpu_stat.compute_breakdown(&cpu_stat))
         })
         .collect();
 
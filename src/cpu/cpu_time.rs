@@ -21,3 +21,20 @@ impl CPUTime {
         self.idle + self.non_idle
     }
 }
+
+/// Fractions of a time interval spent in each CPU state. If a field is `1.0`, means `100%`. `user` and `nice` do not include `guest` and `guest_nice`, so all fields add up to `1.0`.
+#[derive(Default, Debug, Clone)]
+pub struct CPUTimeBreakdown {
+    /// The CPU number. `None` for the aggregate of all CPUs.
+    pub cpu:        Option<usize>,
+    pub user:       f64,
+    pub nice:       f64,
+    pub system:     f64,
+    pub idle:       f64,
+    pub iowait:     f64,
+    pub irq:        f64,
+    pub softirq:    f64,
+    pub steal:      f64,
+    pub guest:      f64,
+    pub guest_nice: f64,
+}
@@ -0,0 +1,208 @@
+use std::{io::ErrorKind, path::Path};
+
+use crate::{
+    cpu::{get_cpu_core_classes_from_path, CPUCoreClass},
+    functions::read_one_value,
+    scanner_rust::ScannerError,
+};
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct LogicalCPUTopology {
+    pub cpu:             usize,
+    pub online:          bool,
+    /// `None` if the CPU is offline and the kernel hides its topology.
+    pub package_id:      Option<usize>,
+    pub die_id:          Option<usize>,
+    pub core_id:         Option<usize>,
+    /// The NUMA node which this CPU belongs to.
+    pub node:            Option<usize>,
+    /// `Unknown` if the CPU is offline.
+    pub core_class:      CPUCoreClass,
+    /// CPUs in the same core (SMT siblings), including this one.
+    pub thread_siblings: Vec<usize>,
+    /// CPUs in the same package, including this one.
+    pub core_siblings:   Vec<usize>,
+    /// CPUs in the same die, including this one.
+    pub die_cpus:        Vec<usize>,
+}
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct NUMANode {
+    pub node: usize,
+    pub cpus: Vec<usize>,
+}
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct CPUTopology {
+    pub online:   Vec<usize>,
+    pub offline:  Vec<usize>,
+    pub present:  Vec<usize>,
+    pub possible: Vec<usize>,
+    /// Every present CPU.
+    pub cpus:     Vec<LogicalCPUTopology>,
+    pub nodes:    Vec<NUMANode>,
+}
+
+/// Parse a CPU list like `0-3,8,10-11` used in the `/sys` folder into CPU numbers.
+///
+/// ```rust
+/// use mprober_lib::cpu;
+///
+/// assert_eq!(vec![0, 1, 2, 3, 8], cpu::parse_cpu_list("0-3,8").unwrap());
+/// ```
+pub fn parse_cpu_list<S: AsRef<str>>(cpu_list: S) -> Result<Vec<usize>, ScannerError> {
+    let cpu_list = cpu_list.as_ref().trim();
+
+    let mut cpus = Vec::new();
+
+    if cpu_list.is_empty() {
+        return Ok(cpus);
+    }
+
+    for range in cpu_list.split(',') {
+        match range.split_once('-') {
+            Some((start, end)) => {
+                let start: usize = start.parse()?;
+                let end: usize = end.parse()?;
+
+                if start > end {
+                    return Err(ErrorKind::InvalidData.into());
+                }
+
+                cpus.extend(start..=end);
+            },
+            None => cpus.push(range.parse()?),
+        }
+    }
+
+    Ok(cpus)
+}
+
+#[inline]
+fn read_cpu_list<P: AsRef<Path>>(path: P) -> Result<Vec<usize>, ScannerError> {
+    match read_one_value(path)? {
+        Some(cpu_list) => parse_cpu_list(cpu_list),
+        None => Ok(Vec::new()),
+    }
+}
+
+#[inline]
+fn read_id<P: AsRef<Path>>(path: P) -> Result<Option<usize>, ScannerError> {
+    match read_one_value(path)? {
+        // it can be -1 if the firmware does not provide it
+        Some(id) => {
+            let id: i64 = id.parse()?;
+
+            Ok(if id < 0 { None } else { Some(id as usize) })
+        },
+        None => Ok(None),
+    }
+}
+
+/// Get NUMA nodes and their CPUs by reading a folder in the format of `/sys/devices/system/node`.
+pub fn get_numa_nodes_from_path<P: AsRef<Path>>(
+    node_path: P,
+) -> Result<Vec<NUMANode>, ScannerError> {
+    let mut nodes = Vec::new();
+
+    let dir = match node_path.as_ref().read_dir() {
+        Ok(dir) => dir,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(nodes),
+        Err(err) => return Err(err.into()),
+    };
+
+    for dir_entry in dir {
+        let dir_entry = dir_entry?;
+
+        if let Some(file_name) = dir_entry.file_name().to_str() {
+            if let Some(node) = file_name.strip_prefix("node") {
+                if let Ok(node) = node.parse::<usize>() {
+                    let cpus = read_cpu_list(dir_entry.path().join("cpulist"))?;
+
+                    nodes.push(NUMANode {
+                        node,
+                        cpus,
+                    });
+                }
+            }
+        }
+    }
+
+    nodes.sort_unstable_by_key(|node| node.node);
+
+    Ok(nodes)
+}
+
+/// Get NUMA nodes and their CPUs by reading the `/sys/devices/system/node` folder. If the kernel does not support NUMA, it returns an empty vector.
+///
+/// ```rust
+/// use mprober_lib::cpu;
+///
+/// let numa_nodes = cpu::get_numa_nodes().unwrap();
+///
+/// println!("{numa_nodes:#?}");
+/// ```
+#[inline]
+pub fn get_numa_nodes() -> Result<Vec<NUMANode>, ScannerError> {
+    get_numa_nodes_from_path("/sys/devices/system/node")
+}
+
+/// Get the CPU topology by reading the `system/cpu` folder and the `system/node` folder in a folder in the format of `/sys/devices`. The core class of each CPU is detected by `get_cpu_core_classes_from_path`.
+pub fn get_cpu_topology_from_path<P: AsRef<Path>>(
+    devices_path: P,
+) -> Result<CPUTopology, ScannerError> {
+    let devices_path = devices_path.as_ref();
+    let cpu_path = devices_path.join("system/cpu");
+
+    let online = read_cpu_list(cpu_path.join("online"))?;
+    let offline = read_cpu_list(cpu_path.join("offline"))?;
+    let present = read_cpu_list(cpu_path.join("present"))?;
+    let possible = read_cpu_list(cpu_path.join("possible"))?;
+
+    let nodes = get_numa_nodes_from_path(devices_path.join("system/node"))?;
+    let core_classes = get_cpu_core_classes_from_path(devices_path)?;
+
+    let mut cpus = Vec::with_capacity(present.len());
+
+    for &cpu in present.iter() {
+        let topology_path = cpu_path.join(format!("cpu{cpu}")).join("topology");
+
+        let node = nodes.iter().find(|node| node.cpus.contains(&cpu)).map(|node| node.node);
+
+        cpus.push(LogicalCPUTopology {
+            cpu,
+            online: online.contains(&cpu),
+            package_id: read_id(topology_path.join("physical_package_id"))?,
+            die_id: read_id(topology_path.join("die_id"))?,
+            core_id: read_id(topology_path.join("core_id"))?,
+            node,
+            core_class: core_classes.get(&cpu).copied().unwrap_or_default(),
+            thread_siblings: read_cpu_list(topology_path.join("thread_siblings_list"))?,
+            core_siblings: read_cpu_list(topology_path.join("core_siblings_list"))?,
+            die_cpus: read_cpu_list(topology_path.join("die_cpus_list"))?,
+        });
+    }
+
+    Ok(CPUTopology {
+        online,
+        offline,
+        present,
+        possible,
+        cpus,
+        nodes,
+    })
+}
+
+/// Get the CPU topology by reading the `/sys/devices/system/cpu` folder and the `/sys/devices/system/node` folder. The core class of each CPU is detected by `get_cpu_core_classes`.
+///
+/// ```rust
+/// use mprober_lib::cpu;
+///
+/// let cpu_topology = cpu::get_cpu_topology().unwrap();
+///
+/// println!("{cpu_topology:#?}");
+/// ```
+#[inline]
+pub fn get_cpu_topology() -> Result<CPUTopology, ScannerError> {
+    get_cpu_topology_from_path("/sys/devices")
+}
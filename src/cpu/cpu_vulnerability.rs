@@ -0,0 +1,179 @@
+use std::{fs, io::ErrorKind, path::Path};
+
+use crate::{functions::read_one_value, scanner_rust::ScannerError};
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum CPUVulnerabilityStatus {
+    NotAffected,
+    Vulnerable,
+    Mitigated,
+    #[default]
+    Unknown,
+}
+
+impl CPUVulnerabilityStatus {
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CPUVulnerabilityStatus::NotAffected => "Not affected",
+            CPUVulnerabilityStatus::Vulnerable => "Vulnerable",
+            CPUVulnerabilityStatus::Mitigated => "Mitigated",
+            CPUVulnerabilityStatus::Unknown => "Unknown",
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct CPUVulnerability {
+    /// The file name, such as `spectre_v2`.
+    pub name:   String,
+    pub status: CPUVulnerabilityStatus,
+    /// The part the status applies to, such as `KVM` in `KVM: Mitigation: VMX disabled`.
+    pub scope:  Option<String>,
+    /// The text after the status, such as the mitigation in use.
+    pub detail: Option<String>,
+    /// The original text of the file.
+    pub raw:    String,
+}
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct CPUVulnerabilities {
+    /// The value of the `mitigations=` kernel parameter, such as `off` or `auto,nosmt`. `None` if it is not set, which means `auto`.
+    pub mitigations:     Option<String>,
+    /// Sorted by names.
+    pub vulnerabilities: Vec<CPUVulnerability>,
+}
+
+impl CPUVulnerability {
+    /// Whether this vulnerability is mitigated but the detail still reports a vulnerable component, such as `Mitigation: Enhanced / Automatic IBRS; BHI: Vulnerable` or `Mitigation: Clear CPU buffers; SMT vulnerable`.
+    #[inline]
+    pub fn is_partially_vulnerable(&self) -> bool {
+        self.status == CPUVulnerabilityStatus::Mitigated
+            && self
+                .detail
+                .as_deref()
+                .map(|detail| detail.to_ascii_lowercase().contains("vulnerable"))
+                .unwrap_or(false)
+    }
+}
+
+impl CPUVulnerabilities {
+    /// Whether any vulnerability is reported as `Vulnerable`. Partially vulnerable ones (see `CPUVulnerability::is_partially_vulnerable`) are not counted.
+    #[inline]
+    pub fn is_vulnerable(&self) -> bool {
+        self.vulnerabilities
+            .iter()
+            .any(|vulnerability| vulnerability.status == CPUVulnerabilityStatus::Vulnerable)
+    }
+}
+
+/// Whether the text starts with a status rather than a scope.
+#[inline]
+fn starts_with_status(s: &str) -> bool {
+    ["Not affected", "Mitigation", "Vulnerable", "Processor vulnerable", "Unknown"]
+        .iter()
+        .any(|status| s.starts_with(status))
+}
+
+fn parse_vulnerability(name: String, raw: String) -> CPUVulnerability {
+    // `KVM: Mitigation: VMX disabled`
+    let (scope, text) = match raw.split_once(": ") {
+        Some((scope, text))
+            if !scope.contains(' ') && !starts_with_status(scope) && starts_with_status(text) =>
+        {
+            (Some(String::from(scope)), text)
+        },
+        _ => (None, raw.as_str()),
+    };
+
+    let (status, detail) = if text == "Not affected" {
+        (CPUVulnerabilityStatus::NotAffected, None)
+    } else if let Some(detail) = text.strip_prefix("Mitigation") {
+        (CPUVulnerabilityStatus::Mitigated, Some(detail))
+    } else if let Some(detail) = text.strip_prefix("Vulnerable") {
+        (CPUVulnerabilityStatus::Vulnerable, Some(detail))
+    } else if text.starts_with("Processor vulnerable") {
+        (CPUVulnerabilityStatus::Vulnerable, Some(text))
+    } else if let Some(detail) = text.strip_prefix("Unknown") {
+        (CPUVulnerabilityStatus::Unknown, Some(detail))
+    } else {
+        (CPUVulnerabilityStatus::Unknown, Some(text))
+    };
+
+    // `Mitigation: PTI`, `Vulnerable: Clear CPU buffers attempted, no microcode`, `Vulnerable; SMT vulnerable`
+    let detail = detail
+        .map(|detail| detail.trim_start_matches([':', ';', ',']).trim())
+        .filter(|detail| !detail.is_empty())
+        .map(String::from);
+
+    CPUVulnerability {
+        name,
+        status,
+        scope,
+        detail,
+        raw,
+    }
+}
+
+/// Get CPU vulnerabilities by reading a folder in the format of `/sys/devices/system/cpu/vulnerabilities` and the `mitigations=` setting from a file in the format of `/proc/cmdline`.
+pub fn get_cpu_vulnerabilities_from_paths<P: AsRef<Path>, CP: AsRef<Path>>(
+    vulnerabilities_path: P,
+    cmdline_path: CP,
+) -> Result<CPUVulnerabilities, ScannerError> {
+    let mut vulnerabilities = Vec::new();
+
+    match vulnerabilities_path.as_ref().read_dir() {
+        Ok(dir) => {
+            for dir_entry in dir {
+                let dir_entry = dir_entry?;
+
+                let name = dir_entry.file_name().to_string_lossy().into_owned();
+
+                if let Some(raw) = read_one_value(dir_entry.path())? {
+                    vulnerabilities.push(parse_vulnerability(name, raw));
+                }
+            }
+        },
+        // kernels before 4.15 do not have this folder
+        Err(err) if err.kind() == ErrorKind::NotFound => (),
+        Err(err) => return Err(err.into()),
+    }
+
+    vulnerabilities.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+
+    let cmdline = fs::read_to_string(cmdline_path)?;
+
+    // the last one takes effect
+    let mitigations = cmdline
+        .split_ascii_whitespace()
+        .take_while(|parameter| *parameter != "--")
+        .filter_map(|parameter| parameter.strip_prefix("mitigations="))
+        .last()
+        .map(String::from);
+
+    Ok(CPUVulnerabilities {
+        mitigations,
+        vulnerabilities,
+    })
+}
+
+/// Get the status of CPU vulnerabilities (Spectre, Meltdown, etc.) by reading the `/sys/devices/system/cpu/vulnerabilities` folder, and the `mitigations=` kernel parameter by reading the `/proc/cmdline` file.
+///
+/// ```rust
+/// use mprober_lib::cpu;
+///
+/// let cpu_vulnerabilities = cpu::get_cpu_vulnerabilities().unwrap();
+///
+/// for vulnerability in cpu_vulnerabilities.vulnerabilities {
+///     println!(
+///         "{}: {} {:?}",
+///         vulnerability.name,
+///         vulnerability.status.as_str(),
+///         vulnerability.detail
+///     );
+/// }
+/// ```
+#[inline]
+pub fn get_cpu_vulnerabilities() -> Result<CPUVulnerabilities, ScannerError> {
+    get_cpu_vulnerabilities_from_paths("/sys/devices/system/cpu/vulnerabilities", "/proc/cmdline")
+}
@@ -0,0 +1,367 @@
+use std::{
+    collections::BTreeSet,
+    hash::{Hash, Hasher},
+    io::ErrorKind,
+    path::Path,
+    str::from_utf8_unchecked,
+};
+
+use crate::scanner_rust::{generic_array::typenum::U1024, ScannerAscii, ScannerError};
+
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct AddressSizes {
+    pub physical_bits: u8,
+    pub virtual_bits:  u8,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct LogicalCPU {
+    pub processor:        usize,
+    pub vendor_id:        Option<String>,
+    pub cpu_family:       Option<u32>,
+    pub model:            Option<u32>,
+    pub model_name:       Option<String>,
+    pub stepping:         Option<u32>,
+    pub microcode:        Option<u64>,
+    pub cpu_mhz:          Option<f64>,
+    /// In bytes.
+    pub cache_size:       Option<u64>,
+    pub physical_id:      Option<usize>,
+    pub siblings:         Option<usize>,
+    pub core_id:          Option<usize>,
+    pub cpu_cores:        Option<usize>,
+    pub apicid:           Option<u32>,
+    pub bogomips:         Option<f64>,
+    pub address_sizes:    Option<AddressSizes>,
+    /// `flags` on x86, `Features` on ARM.
+    pub flags:            BTreeSet<String>,
+    /// ARM only.
+    pub cpu_implementer:  Option<u32>,
+    /// ARM only.
+    pub cpu_architecture: Option<String>,
+    /// ARM only.
+    pub cpu_variant:      Option<u32>,
+    /// ARM only.
+    pub cpu_part:         Option<u32>,
+    /// ARM only.
+    pub cpu_revision:     Option<u32>,
+    /// The SoC name on 32-bit ARM. It is shared by all logical CPUs.
+    pub hardware:         Option<String>,
+    /// RISC-V only.
+    pub isa:              Option<String>,
+    /// RISC-V only.
+    pub mmu:              Option<String>,
+    /// RISC-V only.
+    pub uarch:            Option<String>,
+}
+
+impl LogicalCPU {
+    /// Check whether this logical CPU supports a specific feature flag, such as `avx2`.
+    ///
+    /// ```rust
+    /// use mprober_lib::cpu;
+    ///
+    /// let logical_cpus = cpu::get_logical_cpus().unwrap();
+    ///
+    /// let avx2 =
+    ///     logical_cpus.iter().all(|logical_cpu| logical_cpu.has_flag("avx2"));
+    ///
+    /// println!("{avx2}");
+    /// ```
+    #[inline]
+    pub fn has_flag<S: AsRef<str>>(&self, flag: S) -> bool {
+        self.flags.contains(flag.as_ref())
+    }
+
+    /// Get a human-readable model name. It is the `model name` item on x86. On other architectures which do not have that item, it is derived from the `CPU implementer` and `CPU part` items (ARM), the `Hardware` item (32-bit ARM), or the `uarch` and `isa` items (RISC-V).
+    ///
+    /// ```rust
+    /// use mprober_lib::cpu;
+    ///
+    /// let logical_cpus = cpu::get_logical_cpus().unwrap();
+    ///
+    /// println!("{:?}", logical_cpus[0].get_model_name());
+    /// ```
+    pub fn get_model_name(&self) -> Option<String> {
+        if let Some(model_name) = self.model_name.as_ref() {
+            return Some(model_name.clone());
+        }
+
+        if let Some(implementer) = self.cpu_implementer {
+            let implementer_name = arm_implementer_name(implementer);
+
+            let part_name = self.cpu_part.and_then(|part| arm_part_name(implementer, part));
+
+            return Some(match (implementer_name, part_name) {
+                (Some(implementer_name), Some(part_name)) => {
+                    format!("{implementer_name} {part_name}")
+                },
+                (Some(implementer_name), None) => match self.cpu_part {
+                    Some(part) => format!("{implementer_name} part 0x{part:03x}"),
+                    None => implementer_name.to_string(),
+                },
+                (None, _) => match self.cpu_part {
+                    Some(part) => format!("implementer 0x{implementer:02x} part 0x{part:03x}"),
+                    None => format!("implementer 0x{implementer:02x}"),
+                },
+            });
+        }
+
+        if let Some(hardware) = self.hardware.as_ref() {
+            return Some(hardware.clone());
+        }
+
+        if let Some(uarch) = self.uarch.as_ref() {
+            return Some(uarch.clone());
+        }
+
+        if let Some(isa) = self.isa.as_ref() {
+            return Some(isa.clone());
+        }
+
+        self.vendor_id.clone()
+    }
+}
+
+fn arm_implementer_name(implementer: u32) -> Option<&'static str> {
+    match implementer {
+        0x41 => Some("ARM"),
+        0x42 => Some("Broadcom"),
+        0x43 => Some("Cavium"),
+        0x46 => Some("Fujitsu"),
+        0x48 => Some("HiSilicon"),
+        0x4E => Some("NVIDIA"),
+        0x50 => Some("APM"),
+        0x51 => Some("Qualcomm"),
+        0x53 => Some("Samsung"),
+        0x56 => Some("Marvell"),
+        0x61 => Some("Apple"),
+        0x69 => Some("Intel"),
+        0xC0 => Some("Ampere"),
+        _ => None,
+    }
+}
+
+fn arm_part_name(implementer: u32, part: u32) -> Option<&'static str> {
+    match (implementer, part) {
+        (0x41, 0xB76) => Some("ARM1176"),
+        (0x41, 0xC07) => Some("Cortex-A7"),
+        (0x41, 0xC08) => Some("Cortex-A8"),
+        (0x41, 0xC09) => Some("Cortex-A9"),
+        (0x41, 0xC0F) => Some("Cortex-A15"),
+        (0x41, 0xD03) => Some("Cortex-A53"),
+        (0x41, 0xD04) => Some("Cortex-A35"),
+        (0x41, 0xD05) => Some("Cortex-A55"),
+        (0x41, 0xD07) => Some("Cortex-A57"),
+        (0x41, 0xD08) => Some("Cortex-A72"),
+        (0x41, 0xD09) => Some("Cortex-A73"),
+        (0x41, 0xD0A) => Some("Cortex-A75"),
+        (0x41, 0xD0B) => Some("Cortex-A76"),
+        (0x41, 0xD0C) => Some("Neoverse-N1"),
+        (0x41, 0xD0D) => Some("Cortex-A77"),
+        (0x41, 0xD40) => Some("Neoverse-V1"),
+        (0x41, 0xD41) => Some("Cortex-A78"),
+        (0x41, 0xD44) => Some("Cortex-X1"),
+        (0x41, 0xD46) => Some("Cortex-A510"),
+        (0x41, 0xD47) => Some("Cortex-A710"),
+        (0x41, 0xD48) => Some("Cortex-X2"),
+        (0x41, 0xD49) => Some("Neoverse-N2"),
+        (0x41, 0xD4D) => Some("Cortex-A715"),
+        (0x41, 0xD4E) => Some("Cortex-X3"),
+        (0x41, 0xD4F) => Some("Neoverse-V2"),
+        (0x41, 0xD80) => Some("Cortex-A520"),
+        (0x41, 0xD81) => Some("Cortex-A720"),
+        (0x41, 0xD82) => Some("Cortex-X4"),
+        (0x46, 0x001) => Some("A64FX"),
+        (0x48, 0xD01) => Some("Kunpeng-920"),
+        (0x4E, 0x004) => Some("Carmel"),
+        (0x51, 0x800) => Some("Kryo 2XX Gold"),
+        (0x51, 0x801) => Some("Kryo 2XX Silver"),
+        (0x51, 0xC00) => Some("Falkor"),
+        (0x61, 0x022) => Some("M1 Icestorm"),
+        (0x61, 0x023) => Some("M1 Firestorm"),
+        (0xC0, 0xAC3) => Some("Ampere-1"),
+        (0xC0, 0xAC4) => Some("Ampere-1a"),
+        _ => None,
+    }
+}
+
+impl Hash for LogicalCPU {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.processor.hash(state)
+    }
+}
+
+impl PartialEq for LogicalCPU {
+    #[inline]
+    fn eq(&self, other: &LogicalCPU) -> bool {
+        self.processor.eq(&other.processor)
+    }
+}
+
+#[inline]
+fn parse_hex(value: &str) -> Option<u64> {
+    u64::from_str_radix(value.trim_start_matches("0x"), 16).ok()
+}
+
+/// `0x41` or `65` -> `65`
+#[inline]
+fn parse_number(value: &str) -> Option<u32> {
+    if let Some(value) = value.strip_prefix("0x") {
+        u32::from_str_radix(value, 16).ok()
+    } else {
+        value.parse().ok()
+    }
+}
+
+/// `107520 KB` -> `110100480`
+#[inline]
+fn parse_size(value: &str) -> Option<u64> {
+    let mut tokens = value.split_ascii_whitespace();
+
+    let size: u64 = tokens.next()?.parse().ok()?;
+
+    match tokens.next() {
+        Some("KB") | Some("kB") | Some("K") => Some(size * 1024),
+        Some("MB") | Some("M") => Some(size * 1024 * 1024),
+        None => Some(size),
+        _ => None,
+    }
+}
+
+/// `46 bits physical, 57 bits virtual`
+#[inline]
+fn parse_address_sizes(value: &str) -> Option<AddressSizes> {
+    let (physical, virtual_) = value.split_once(',')?;
+
+    let physical_bits = physical.split_ascii_whitespace().next()?.parse().ok()?;
+    let virtual_bits = virtual_.split_ascii_whitespace().next()?.parse().ok()?;
+
+    Some(AddressSizes {
+        physical_bits,
+        virtual_bits,
+    })
+}
+
+/// Get information of every logical CPU (processor) by reading a file in the format of `/proc/cpuinfo`.
+///
+/// ```rust
+/// use mprober_lib::cpu;
+///
+/// let logical_cpus =
+///     cpu::get_logical_cpus_from_path("/proc/cpuinfo").unwrap();
+///
+/// println!("{logical_cpus:#?}");
+/// ```
+pub fn get_logical_cpus_from_path<P: AsRef<Path>>(
+    cpuinfo_path: P,
+) -> Result<Vec<LogicalCPU>, ScannerError> {
+    let mut sc: ScannerAscii<_, U1024> = ScannerAscii::scan_path2(cpuinfo_path)?;
+
+    let mut logical_cpus = Vec::with_capacity(1);
+
+    let mut logical_cpu: Option<LogicalCPU> = None;
+    let mut hardware: Option<String> = None;
+
+    while let Some(line) = sc.next_line_raw()? {
+        let line = unsafe { from_utf8_unchecked(&line) };
+
+        let (key, value) = match line.split_once(':') {
+            Some((key, value)) => (key.trim(), value.trim()),
+            None => {
+                // a blank line ends the block of a logical CPU
+                if let Some(logical_cpu) = logical_cpu.take() {
+                    logical_cpus.push(logical_cpu);
+                }
+
+                continue;
+            },
+        };
+
+        if key == "processor" {
+            if let Some(logical_cpu) = logical_cpu.take() {
+                logical_cpus.push(logical_cpu);
+            }
+
+            logical_cpu = Some(LogicalCPU {
+                processor: value.parse()?,
+                ..LogicalCPU::default()
+            });
+
+            continue;
+        }
+
+        let logical_cpu = match logical_cpu.as_mut() {
+            Some(logical_cpu) => logical_cpu,
+            None => {
+                // items which are not in any block of a logical CPU
+                if key == "Hardware" {
+                    hardware = Some(value.to_string());
+                }
+
+                continue;
+            },
+        };
+
+        match key {
+            "vendor_id" => logical_cpu.vendor_id = Some(value.to_string()),
+            "cpu family" => logical_cpu.cpu_family = value.parse().ok(),
+            "model" => logical_cpu.model = value.parse().ok(),
+            "model name" => logical_cpu.model_name = Some(value.to_string()),
+            "stepping" => logical_cpu.stepping = value.parse().ok(),
+            "microcode" => logical_cpu.microcode = parse_hex(value),
+            "cpu MHz" => logical_cpu.cpu_mhz = value.parse().ok(),
+            "cache size" => logical_cpu.cache_size = parse_size(value),
+            "physical id" => logical_cpu.physical_id = value.parse().ok(),
+            "siblings" => logical_cpu.siblings = value.parse().ok(),
+            "core id" => logical_cpu.core_id = value.parse().ok(),
+            "cpu cores" => logical_cpu.cpu_cores = value.parse().ok(),
+            "apicid" => logical_cpu.apicid = value.parse().ok(),
+            "bogomips" | "BogoMIPS" => logical_cpu.bogomips = value.parse().ok(),
+            "address sizes" => logical_cpu.address_sizes = parse_address_sizes(value),
+            "flags" | "Features" => {
+                logical_cpu.flags = value.split_ascii_whitespace().map(String::from).collect()
+            },
+            "CPU implementer" => logical_cpu.cpu_implementer = parse_number(value),
+            "CPU architecture" => logical_cpu.cpu_architecture = Some(value.to_string()),
+            "CPU variant" => logical_cpu.cpu_variant = parse_number(value),
+            "CPU part" => logical_cpu.cpu_part = parse_number(value),
+            "CPU revision" => logical_cpu.cpu_revision = parse_number(value),
+            "isa" => logical_cpu.isa = Some(value.to_string()),
+            "mmu" => logical_cpu.mmu = Some(value.to_string()),
+            "uarch" => logical_cpu.uarch = Some(value.to_string()),
+            _ => (),
+        }
+    }
+
+    if let Some(logical_cpu) = logical_cpu {
+        logical_cpus.push(logical_cpu);
+    }
+
+    if logical_cpus.is_empty() {
+        return Err(ErrorKind::UnexpectedEof.into());
+    }
+
+    if hardware.is_some() {
+        for logical_cpu in logical_cpus.iter_mut() {
+            logical_cpu.hardware = hardware.clone();
+        }
+    }
+
+    Ok(logical_cpus)
+}
+
+/// Get information of every logical CPU (processor) by reading the `/proc/cpuinfo` file.
+///
+/// ```rust
+/// use mprober_lib::cpu;
+///
+/// let logical_cpus = cpu::get_logical_cpus().unwrap();
+///
+/// println!("{logical_cpus:#?}");
+/// ```
+#[inline]
+pub fn get_logical_cpus() -> Result<Vec<LogicalCPU>, ScannerError> {
+    get_logical_cpus_from_path("/proc/cpuinfo")
+}
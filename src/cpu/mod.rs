@@ -1,7 +1,23 @@
+mod cpu_cache;
+mod cpu_core_class;
+mod cpu_frequency;
+mod cpu_idle;
 mod cpu_info;
+mod cpu_sched_stat;
 mod cpu_stat;
 mod cpu_time;
+mod cpu_topology;
+mod cpu_vulnerability;
+mod logical_cpu;
 
+pub use cpu_cache::*;
+pub use cpu_core_class::*;
+pub use cpu_frequency::*;
+pub use cpu_idle::*;
 pub use cpu_info::*;
+pub use cpu_sched_stat::*;
 pub use cpu_stat::*;
 pub use cpu_time::*;
+pub use cpu_topology::*;
+pub use cpu_vulnerability::*;
+pub use logical_cpu::*;
@@ -1,4 +1,10 @@
-use std::{fmt::Write, time::Duration};
+use std::{
+    fmt::Write,
+    fs,
+    io::{self, ErrorKind},
+    path::Path,
+    time::Duration,
+};
 
 /// Format a `Duration` to a string. The string would be like `4 hours, 39 minutes, and 25 seconds`.
 ///
@@ -74,3 +80,21 @@ pub fn format_duration(duration: Duration) -> String {
         String::from_utf8_unchecked(v)
     }
 }
+
+/// Read a file which contains only one value, such as files in the `/sys` folder. The value is trimmed. If the file does not exist, it returns `None`.
+pub(crate) fn read_one_value<P: AsRef<Path>>(path: P) -> Result<Option<String>, io::Error> {
+    match fs::read_to_string(path) {
+        Ok(value) => Ok(Some(value.trim().to_string())),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Read a file which lists options and marks the selected one with brackets, such as `always [madvise] never`. If the file does not exist or no option is selected, it returns `None`.
+pub(crate) fn read_selected_value<P: AsRef<Path>>(path: P) -> Result<Option<String>, io::Error> {
+    Ok(read_one_value(path)?.and_then(|options| {
+        options
+            .split_ascii_whitespace()
+            .find_map(|option| option.strip_prefix('[')?.strip_suffix(']').map(String::from))
+    }))
+}
@@ -0,0 +1,254 @@
+use std::{
+    collections::BTreeMap, io::ErrorKind, path::Path, str::from_utf8_unchecked, thread::sleep,
+    time::Duration,
+};
+
+use crate::{
+    cpu::parse_cpu_list,
+    functions::read_one_value,
+    interrupt::{compute_rate, parse_cpu_header, InterruptRate},
+    scanner_rust::{generic_array::typenum::U1024, ScannerAscii, ScannerError},
+};
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct Interrupt {
+    /// The IRQ number such as `24`, or the name of an architecture-specific interrupt such as `NMI` and `LOC`.
+    pub irq:          String,
+    /// The numbers of interrupts serviced on each CPU since boot. The keys are CPU numbers. It is empty for interrupts which only have a total (e.g. `ERR` and `MIS`).
+    pub counts:       BTreeMap<usize, u64>,
+    pub total:        u64,
+    /// The interrupt controller, such as `IO-APIC`, `PCI-MSIX-0000:00:01.0` or `GICv3`.
+    pub chip:         Option<String>,
+    /// The hardware IRQ number inside the interrupt controller.
+    pub hwirq:        Option<String>,
+    /// The trigger type, such as `edge` or `Level`.
+    pub trigger_type: Option<String>,
+    /// The device names which use this IRQ.
+    pub devices:      Vec<String>,
+    /// The description of an architecture-specific interrupt, such as `Non-maskable interrupts`.
+    pub description:  Option<String>,
+    /// CPUs which this IRQ can be delivered to. (`/proc/irq/N/smp_affinity_list`) `None` if the IRQ is not numbered or the affinity is unknown.
+    pub affinity:     Option<Vec<usize>>,
+}
+
+impl Interrupt {
+    /// The IRQ number. `None` for architecture-specific interrupts.
+    #[inline]
+    pub fn get_irq_number(&self) -> Option<usize> {
+        self.irq.parse().ok()
+    }
+
+    /// Calculate rates per second between two `Interrupt` instances at different time.
+    #[inline]
+    pub fn compute_rate(
+        &self,
+        interrupt_after_this: &Interrupt,
+        interval: Duration,
+    ) -> InterruptRate {
+        compute_rate(
+            &self.counts,
+            self.total,
+            &interrupt_after_this.counts,
+            interrupt_after_this.total,
+            interval,
+        )
+    }
+}
+
+/// `5-edge` -> `("5", "edge")`
+#[inline]
+fn split_hwirq_type(s: &str) -> Option<(&str, &str)> {
+    match s.split_once('-') {
+        Some((hwirq, trigger_type))
+            if !hwirq.is_empty() && hwirq.bytes().all(|e| e.is_ascii_digit()) =>
+        {
+            Some((hwirq, trigger_type))
+        },
+        _ => None,
+    }
+}
+
+fn parse_interrupt(line: &str, cpus: &[usize]) -> Option<Interrupt> {
+    let mut tokens = line.split_ascii_whitespace().peekable();
+
+    let irq = tokens.next()?.trim_end_matches(':');
+
+    let mut values = Vec::with_capacity(cpus.len());
+
+    while values.len() < cpus.len() {
+        match tokens.peek().and_then(|token| token.parse::<u64>().ok()) {
+            Some(value) => {
+                values.push(value);
+                tokens.next();
+            },
+            None => break,
+        }
+    }
+
+    let total = values.iter().sum();
+
+    let counts = if values.len() == cpus.len() {
+        cpus.iter().copied().zip(values).collect()
+    } else {
+        BTreeMap::new()
+    };
+
+    let rest: Vec<&str> = tokens.collect();
+
+    let mut interrupt = Interrupt {
+        irq: String::from(irq),
+        counts,
+        total,
+        ..Interrupt::default()
+    };
+
+    if irq.bytes().all(|e| e.is_ascii_digit()) {
+        let mut index = 0;
+
+        if let Some(chip) = rest.first() {
+            interrupt.chip = Some(String::from(*chip));
+            index = 1;
+
+            if let Some((hwirq, trigger_type)) = rest.get(1).and_then(|s| split_hwirq_type(s)) {
+                // x86: `IO-APIC   5-edge      ACPI:Ged`
+                interrupt.hwirq = Some(String::from(hwirq));
+                interrupt.trigger_type = Some(String::from(trigger_type));
+                index = 2;
+            } else if let (Some(hwirq), Some(trigger_type)) = (rest.get(1), rest.get(2)) {
+                // ARM: `GICv3  27 Level     arch_timer`
+                if hwirq.bytes().all(|e| e.is_ascii_digit())
+                    && matches!(*trigger_type, "Level" | "Edge")
+                {
+                    interrupt.hwirq = Some(String::from(*hwirq));
+                    interrupt.trigger_type = Some(String::from(*trigger_type));
+                    index = 3;
+                }
+            }
+        }
+
+        interrupt.devices = rest[index..]
+            .join(" ")
+            .split(',')
+            .map(|device| device.trim())
+            .filter(|device| !device.is_empty())
+            .map(String::from)
+            .collect();
+    } else if !rest.is_empty() {
+        interrupt.description = Some(rest.join(" "));
+    }
+
+    Some(interrupt)
+}
+
+/// Get the CPUs which an IRQ can be delivered to by reading the `/proc/irq/N/smp_affinity_list` file. If the IRQ does not exist, it returns `None`.
+///
+/// ```rust
+/// use mprober_lib::interrupt;
+///
+/// let affinity = interrupt::get_irq_affinity(0).unwrap();
+///
+/// println!("{affinity:?}");
+/// ```
+#[inline]
+pub fn get_irq_affinity(irq: usize) -> Result<Option<Vec<usize>>, ScannerError> {
+    get_irq_affinity_from_path(Path::new("/proc/irq").join(irq.to_string()))
+}
+
+#[inline]
+fn get_irq_affinity_from_path<P: AsRef<Path>>(
+    irq_path: P,
+) -> Result<Option<Vec<usize>>, ScannerError> {
+    match read_one_value(irq_path.as_ref().join("smp_affinity_list")) {
+        Ok(Some(affinity)) => Ok(Some(parse_cpu_list(affinity)?)),
+        Ok(None) => Ok(None),
+        Err(err) if err.kind() == ErrorKind::PermissionDenied => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Get interrupt counters by reading a file in the format of `/proc/interrupts` and the affinity of each IRQ from a folder in the format of `/proc/irq`.
+pub fn get_interrupts_from_paths<P: AsRef<Path>, IP: AsRef<Path>>(
+    interrupts_path: P,
+    irq_path: IP,
+) -> Result<Vec<Interrupt>, ScannerError> {
+    let irq_path = irq_path.as_ref();
+
+    let mut sc: ScannerAscii<_, U1024> = ScannerAscii::scan_path2(interrupts_path)?;
+
+    let header = sc.next_line_raw()?.ok_or(ErrorKind::UnexpectedEof)?;
+
+    let cpus = parse_cpu_header(unsafe { from_utf8_unchecked(&header) });
+
+    let mut interrupts = Vec::new();
+
+    while let Some(line) = sc.next_line_raw()? {
+        if let Some(mut interrupt) = parse_interrupt(unsafe { from_utf8_unchecked(&line) }, &cpus) {
+            if interrupt.get_irq_number().is_some() {
+                interrupt.affinity = get_irq_affinity_from_path(irq_path.join(&interrupt.irq))?;
+            }
+
+            interrupts.push(interrupt);
+        }
+    }
+
+    Ok(interrupts)
+}
+
+/// Get interrupt counters of each IRQ and CPU by reading the `/proc/interrupts` file, and the affinity of each IRQ by reading the `/proc/irq/N/smp_affinity_list` files.
+///
+/// ```rust
+/// use mprober_lib::interrupt;
+///
+/// let interrupts = interrupt::get_interrupts().unwrap();
+///
+/// for interrupt in interrupts {
+///     println!(
+///         "{}: {:?} {:?}",
+///         interrupt.irq, interrupt.devices, interrupt.affinity
+///     );
+/// }
+/// ```
+#[inline]
+pub fn get_interrupts() -> Result<Vec<Interrupt>, ScannerError> {
+    get_interrupts_from_paths("/proc/interrupts", "/proc/irq")
+}
+
+/// Get interrupt counters and their rates per second on each CPU within a specific time interval by reading the `/proc/interrupts` file. It will cause the current thread to sleep.
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use mprober_lib::interrupt;
+///
+/// let interrupts_with_rate =
+///     interrupt::get_interrupts_with_rate(Duration::from_millis(100))
+///         .unwrap();
+///
+/// for (interrupt, rate) in interrupts_with_rate {
+///     println!("{}: {:.1}/s", interrupt.irq, rate.total);
+/// }
+/// ```
+pub fn get_interrupts_with_rate(
+    interval: Duration,
+) -> Result<Vec<(Interrupt, InterruptRate)>, ScannerError> {
+    let pre_interrupts = get_interrupts()?;
+
+    sleep(interval);
+
+    let interrupts = get_interrupts()?;
+
+    let result = interrupts
+        .into_iter()
+        .filter_map(|interrupt| {
+            pre_interrupts.iter().find(|pre_interrupt| pre_interrupt.irq == interrupt.irq).map(
+                |pre_interrupt| {
+                    let rate = pre_interrupt.compute_rate(&interrupt, interval);
+
+                    (interrupt, rate)
+                },
+            )
+        })
+        .collect();
+
+    Ok(result)
+}
@@ -0,0 +1,42 @@
+use std::{collections::BTreeMap, time::Duration};
+
+#[derive(Default, Debug, Clone)]
+pub struct InterruptRate {
+    /// Interrupts per second on all CPUs.
+    pub total:   f64,
+    /// Interrupts per second on each CPU. The keys are CPU numbers.
+    pub per_cpu: BTreeMap<usize, f64>,
+}
+
+pub(crate) fn compute_rate(
+    pre_counts: &BTreeMap<usize, u64>,
+    pre_total: u64,
+    counts: &BTreeMap<usize, u64>,
+    total: u64,
+    interval: Duration,
+) -> InterruptRate {
+    let seconds = interval.as_secs_f64();
+
+    // CPUs which are not online during the whole interval are not included
+    let per_cpu = counts
+        .iter()
+        .filter_map(|(cpu, count)| {
+            pre_counts
+                .get(cpu)
+                .map(|pre_count| (*cpu, count.saturating_sub(*pre_count) as f64 / seconds))
+        })
+        .collect();
+
+    InterruptRate {
+        total: total.saturating_sub(pre_total) as f64 / seconds,
+        per_cpu,
+    }
+}
+
+/// `CPU0 CPU1 CPU3` -> `[0, 1, 3]`
+pub(crate) fn parse_cpu_header(header: &str) -> Vec<usize> {
+    header
+        .split_ascii_whitespace()
+        .filter_map(|cpu| cpu.strip_prefix("CPU").and_then(|cpu| cpu.parse().ok()))
+        .collect()
+}
@@ -0,0 +1,8 @@
+#[allow(clippy::module_inception)]
+mod interrupt;
+mod interrupt_rate;
+mod soft_irq;
+
+pub use interrupt::*;
+pub use interrupt_rate::*;
+pub use soft_irq::*;
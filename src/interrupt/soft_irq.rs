@@ -0,0 +1,131 @@
+use std::{
+    collections::BTreeMap, io::ErrorKind, path::Path, str::from_utf8_unchecked, thread::sleep,
+    time::Duration,
+};
+
+use crate::{
+    interrupt::{compute_rate, parse_cpu_header, InterruptRate},
+    scanner_rust::{generic_array::typenum::U1024, ScannerAscii, ScannerError},
+};
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct SoftIRQ {
+    /// The type of the softirq, such as `NET_RX`.
+    pub name:   String,
+    /// The numbers of softirqs serviced on each CPU since boot. The keys are CPU numbers.
+    pub counts: BTreeMap<usize, u64>,
+    pub total:  u64,
+}
+
+impl SoftIRQ {
+    /// Calculate rates per second between two `SoftIRQ` instances at different time.
+    #[inline]
+    pub fn compute_rate(&self, soft_irq_after_this: &SoftIRQ, interval: Duration) -> InterruptRate {
+        compute_rate(
+            &self.counts,
+            self.total,
+            &soft_irq_after_this.counts,
+            soft_irq_after_this.total,
+            interval,
+        )
+    }
+}
+
+/// Get softirq counters by reading a file in the format of `/proc/softirqs`.
+pub fn get_softirqs_from_path<P: AsRef<Path>>(path: P) -> Result<Vec<SoftIRQ>, ScannerError> {
+    let mut sc: ScannerAscii<_, U1024> = ScannerAscii::scan_path2(path)?;
+
+    let header = sc.next_line_raw()?.ok_or(ErrorKind::UnexpectedEof)?;
+
+    let cpus = parse_cpu_header(unsafe { from_utf8_unchecked(&header) });
+
+    let mut softirqs = Vec::new();
+
+    while let Some(line) = sc.next_line_raw()? {
+        let line = unsafe { from_utf8_unchecked(&line) };
+
+        let mut tokens = line.split_ascii_whitespace();
+
+        let name = match tokens.next() {
+            Some(name) => name.trim_end_matches(':'),
+            None => continue,
+        };
+
+        let mut counts = BTreeMap::new();
+        let mut total = 0u64;
+
+        for &cpu in cpus.iter() {
+            let count: u64 = tokens.next().ok_or(ErrorKind::UnexpectedEof)?.parse()?;
+
+            counts.insert(cpu, count);
+            total += count;
+        }
+
+        softirqs.push(SoftIRQ {
+            name: String::from(name),
+            counts,
+            total,
+        });
+    }
+
+    Ok(softirqs)
+}
+
+/// Get softirq counters of each type and CPU by reading the `/proc/softirqs` file.
+///
+/// ```rust
+/// use mprober_lib::interrupt;
+///
+/// let softirqs = interrupt::get_softirqs().unwrap();
+///
+/// for softirq in softirqs {
+///     println!("{}: {:?}", softirq.name, softirq.counts);
+/// }
+/// ```
+#[inline]
+pub fn get_softirqs() -> Result<Vec<SoftIRQ>, ScannerError> {
+    get_softirqs_from_path("/proc/softirqs")
+}
+
+/// Get softirq counters and their rates per second on each CPU within a specific time interval by reading the `/proc/softirqs` file. It will cause the current thread to sleep.
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use mprober_lib::interrupt;
+///
+/// let softirqs_with_rate =
+///     interrupt::get_softirqs_with_rate(Duration::from_millis(100)).unwrap();
+///
+/// for (softirq, rate) in softirqs_with_rate {
+///     if softirq.name == "NET_RX" {
+///         for (cpu, per_second) in rate.per_cpu {
+///             println!("cpu{cpu}: {per_second:.1}/s");
+///         }
+///     }
+/// }
+/// ```
+pub fn get_softirqs_with_rate(
+    interval: Duration,
+) -> Result<Vec<(SoftIRQ, InterruptRate)>, ScannerError> {
+    let pre_softirqs = get_softirqs()?;
+
+    sleep(interval);
+
+    let softirqs = get_softirqs()?;
+
+    let result = softirqs
+        .into_iter()
+        .filter_map(|softirq| {
+            pre_softirqs.iter().find(|pre_softirq| pre_softirq.name == softirq.name).map(
+                |pre_softirq| {
+                    let rate = pre_softirq.compute_rate(&softirq, interval);
+
+                    (softirq, rate)
+                },
+            )
+        })
+        .collect();
+
+    Ok(result)
+}
@@ -0,0 +1,166 @@
+use std::{io::ErrorKind, path::Path, str::from_utf8_unchecked, thread::sleep, time::Duration};
+
+use crate::scanner_rust::{generic_array::typenum::U1024, ScannerAscii, ScannerError};
+
+#[derive(Default, Debug, Clone)]
+pub struct KernelStatRate {
+    /// Context switches per second.
+    pub context_switches: f64,
+    /// Forks (created processes and threads) per second.
+    pub forks:            f64,
+    /// Interrupts per second.
+    pub interrupts:       f64,
+    /// Softirqs per second.
+    pub softirqs:         f64,
+}
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct KernelStat {
+    /// The total number of interrupts serviced since boot. (`intr`)
+    pub interrupts:       u64,
+    /// The total number of context switches since boot. (`ctxt`)
+    pub context_switches: u64,
+    /// The boot time, in seconds since the unix epoch. (`btime`)
+    pub btime:            u64,
+    /// The total number of forks since boot. (`processes`)
+    pub processes:        u64,
+    /// The number of runnable threads.
+    pub procs_running:    u64,
+    /// The number of threads blocked waiting for I/O.
+    pub procs_blocked:    u64,
+    /// The total number of softirqs serviced since boot. (`softirq`)
+    pub softirqs:         u64,
+    /// The numbers of softirqs serviced since boot of each type, in the kernel order (`HI`, `TIMER`, `NET_TX`, `NET_RX`, `BLOCK`, `IRQ_POLL`, `TASKLET`, `SCHED`, `HRTIMER`, `RCU`).
+    pub softirqs_by_type: Vec<u64>,
+}
+
+impl KernelStat {
+    /// Calculate rates per second between two `KernelStat` instances at different time.
+    ///
+    /// ```rust
+    /// use std::{thread::sleep, time::Duration};
+    ///
+    /// use mprober_lib::kernel;
+    ///
+    /// let pre_kernel_stat = kernel::get_kernel_stat().unwrap();
+    ///
+    /// let interval = Duration::from_millis(100);
+    ///
+    /// sleep(interval);
+    ///
+    /// let kernel_stat = kernel::get_kernel_stat().unwrap();
+    ///
+    /// let kernel_stat_rate = pre_kernel_stat.compute_rate(&kernel_stat, interval);
+    ///
+    /// println!("Context switches: {:.1}/s", kernel_stat_rate.context_switches);
+    /// println!("Forks: {:.1}/s", kernel_stat_rate.forks);
+    /// ```
+    #[inline]
+    pub fn compute_rate(
+        &self,
+        kernel_stat_after_this: &KernelStat,
+        interval: Duration,
+    ) -> KernelStatRate {
+        let seconds = interval.as_secs_f64();
+
+        let rate = |before: u64, after: u64| after.saturating_sub(before) as f64 / seconds;
+
+        KernelStatRate {
+            context_switches: rate(self.context_switches, kernel_stat_after_this.context_switches),
+            forks:            rate(self.processes, kernel_stat_after_this.processes),
+            interrupts:       rate(self.interrupts, kernel_stat_after_this.interrupts),
+            softirqs:         rate(self.softirqs, kernel_stat_after_this.softirqs),
+        }
+    }
+}
+
+/// Get kernel and scheduler counters by reading a file in the format of `/proc/stat`. The `cpu` lines are skipped.
+pub fn get_kernel_stat_from_path<P: AsRef<Path>>(path: P) -> Result<KernelStat, ScannerError> {
+    let mut sc: ScannerAscii<_, U1024> = ScannerAscii::scan_path2(path)?;
+
+    let mut kernel_stat = KernelStat::default();
+
+    while let Some(label) = sc.next_raw()? {
+        match label.as_slice() {
+            b"intr" => {
+                kernel_stat.interrupts = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+            },
+            b"ctxt" => {
+                kernel_stat.context_switches = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+            },
+            b"btime" => {
+                kernel_stat.btime = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+            },
+            b"processes" => {
+                kernel_stat.processes = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+            },
+            b"procs_running" => {
+                kernel_stat.procs_running = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+            },
+            b"procs_blocked" => {
+                kernel_stat.procs_blocked = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+            },
+            b"softirq" => {
+                kernel_stat.softirqs = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+
+                let line = sc.next_line_raw()?.ok_or(ErrorKind::UnexpectedEof)?;
+
+                for count in line.split(|&e| e == b' ').filter(|count| !count.is_empty()) {
+                    kernel_stat
+                        .softirqs_by_type
+                        .push(unsafe { from_utf8_unchecked(count) }.parse()?);
+                }
+
+                continue;
+            },
+            _ => (),
+        }
+
+        sc.drop_next_line()?;
+    }
+
+    Ok(kernel_stat)
+}
+
+/// Get kernel and scheduler counters by reading the `/proc/stat` file.
+///
+/// ```rust
+/// use mprober_lib::kernel;
+///
+/// let kernel_stat = kernel::get_kernel_stat().unwrap();
+///
+/// println!("{kernel_stat:#?}");
+/// ```
+#[inline]
+pub fn get_kernel_stat() -> Result<KernelStat, ScannerError> {
+    get_kernel_stat_from_path("/proc/stat")
+}
+
+/// Get kernel and scheduler counters and their rates per second within a specific time interval by reading the `/proc/stat` file. It will cause the current thread to sleep.
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use mprober_lib::kernel;
+///
+/// let (kernel_stat, kernel_stat_rate) =
+///     kernel::get_kernel_stat_with_rate(Duration::from_millis(100)).unwrap();
+///
+/// println!("Running: {}", kernel_stat.procs_running);
+/// println!("Forks: {:.1}/s", kernel_stat_rate.forks);
+/// println!("Interrupts: {:.1}/s", kernel_stat_rate.interrupts);
+/// ```
+#[inline]
+pub fn get_kernel_stat_with_rate(
+    interval: Duration,
+) -> Result<(KernelStat, KernelStatRate), ScannerError> {
+    let pre_kernel_stat = get_kernel_stat()?;
+
+    sleep(interval);
+
+    let kernel_stat = get_kernel_stat()?;
+
+    let kernel_stat_rate = pre_kernel_stat.compute_rate(&kernel_stat, interval);
+
+    Ok((kernel_stat, kernel_stat_rate))
+}
@@ -1,5 +1,9 @@
+mod kernel_stat;
+
 use std::io::ErrorKind;
 
+pub use kernel_stat::*;
+
 use crate::scanner_rust::{generic_array::typenum::U48, ScannerAscii, ScannerError};
 
 /// Get the kernel version by reading the `/proc/version` file.
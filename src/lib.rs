@@ -35,10 +35,12 @@ mod functions;
 pub mod btime;
 pub mod cpu;
 pub mod hostname;
+pub mod interrupt;
 pub mod kernel;
 pub mod load_average;
 pub mod memory;
 pub mod network;
+pub mod pressure;
 pub mod process;
 pub mod rtc_time;
 pub mod uptime;
@@ -0,0 +1,400 @@
+use std::{collections::BTreeMap, io::ErrorKind, path::Path, str::from_utf8_unchecked};
+
+use page_size::get as get_page_size;
+
+use crate::scanner_rust::{
+    generic_array::typenum::{U1024, U256},
+    ScannerAscii, ScannerError,
+};
+
+/// Free blocks of a memory zone in the buddy allocator.
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct BuddyInfo {
+    pub node:        usize,
+    /// Such as `DMA`, `DMA32` or `Normal`.
+    pub zone:        String,
+    /// The number of free blocks of each order. A block of order `n` has `2^n` pages.
+    pub free_blocks: Vec<u64>,
+}
+
+impl BuddyInfo {
+    /// The number of free pages in this zone.
+    #[inline]
+    pub fn get_free_pages(&self) -> u64 {
+        self.free_blocks.iter().enumerate().map(|(order, count)| count << order).sum()
+    }
+
+    /// The fraction of free pages which can not be used for an allocation of the given order, because they are in smaller blocks. `0.0` means there is no fragmentation. As the kernel does, a zone without free pages is fully unusable, so it returns `1.0` for that case.
+    pub fn get_unusable_index(&self, order: usize) -> f64 {
+        let free_pages = self.get_free_pages();
+
+        if free_pages == 0 {
+            return 1.0;
+        }
+
+        let suitable_pages: u64 = self
+            .free_blocks
+            .iter()
+            .enumerate()
+            .skip(order)
+            .map(|(order, count)| count << order)
+            .sum();
+
+        (free_pages - suitable_pages) as f64 / free_pages as f64
+    }
+
+    /// Calculate the fragmentation index which the kernel uses to decide between compaction and reclaim when an allocation of the given order fails. A value near `0.0` means the failure is due to lack of memory, and a value near `1.0` means it is due to fragmentation. If there is a free block large enough, or the order is not less than the number of orders of the zone, it returns `None`.
+    ///
+    /// ```rust
+    /// use mprober_lib::memory;
+    ///
+    /// let buddy_info = memory::get_buddy_info().unwrap();
+    ///
+    /// for zone in buddy_info {
+    ///     println!(
+    ///         "node{} {}: {:?}",
+    ///         zone.node,
+    ///         zone.zone,
+    ///         zone.get_fragmentation_index(9)
+    ///     );
+    /// }
+    /// ```
+    pub fn get_fragmentation_index(&self, order: usize) -> Option<f64> {
+        if order >= self.free_blocks.len() {
+            return None;
+        }
+
+        if self.free_blocks.iter().skip(order).any(|count| *count > 0) {
+            return None;
+        }
+
+        let free_blocks_total: u64 = self.free_blocks.iter().sum();
+
+        if free_blocks_total == 0 {
+            return Some(0.0);
+        }
+
+        let requested = (1u64 << order) as f64;
+
+        Some(1.0 - (1.0 + self.get_free_pages() as f64 / requested) / free_blocks_total as f64)
+    }
+}
+
+/// `Node 0, zone      DMA` -> `(0, "DMA")`
+fn parse_node_zone<'a, I: Iterator<Item = &'a str>>(
+    tokens: &mut I,
+) -> Result<(usize, String), ScannerError> {
+    if tokens.next() != Some("Node") {
+        return Err(ErrorKind::InvalidData.into());
+    }
+
+    let node = tokens.next().ok_or(ErrorKind::UnexpectedEof)?.trim_end_matches(',').parse()?;
+
+    if tokens.next() != Some("zone") {
+        return Err(ErrorKind::InvalidData.into());
+    }
+
+    let zone = tokens.next().ok_or(ErrorKind::UnexpectedEof)?.trim_end_matches(',');
+
+    Ok((node, String::from(zone)))
+}
+
+/// Get free blocks of each memory zone by reading a file in the format of `/proc/buddyinfo`.
+pub fn get_buddy_info_from_path<P: AsRef<Path>>(path: P) -> Result<Vec<BuddyInfo>, ScannerError> {
+    let mut sc: ScannerAscii<_, U256> = ScannerAscii::scan_path2(path)?;
+
+    let mut buddy_info = Vec::new();
+
+    while let Some(line) = sc.next_line_raw()? {
+        let line = unsafe { from_utf8_unchecked(&line) };
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut tokens = line.split_ascii_whitespace();
+
+        let (node, zone) = parse_node_zone(&mut tokens)?;
+
+        let free_blocks = tokens.map(|count| count.parse()).collect::<Result<_, _>>()?;
+
+        buddy_info.push(BuddyInfo {
+            node,
+            zone,
+            free_blocks,
+        });
+    }
+
+    Ok(buddy_info)
+}
+
+/// Get free blocks of each memory zone by reading the `/proc/buddyinfo` file.
+///
+/// ```rust
+/// use mprober_lib::memory;
+///
+/// let buddy_info = memory::get_buddy_info().unwrap();
+///
+/// for zone in buddy_info {
+///     println!("node{} {}: {:?}", zone.node, zone.zone, zone.free_blocks);
+/// }
+/// ```
+#[inline]
+pub fn get_buddy_info() -> Result<Vec<BuddyInfo>, ScannerError> {
+    get_buddy_info_from_path("/proc/buddyinfo")
+}
+
+/// Free blocks and page blocks of a memory zone, grouped by migrate types such as `Unmovable`, `Movable` and `Reclaimable`.
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct PageTypeZone {
+    pub node:        usize,
+    pub zone:        String,
+    /// The number of free blocks of each order, by migrate type.
+    pub free_blocks: BTreeMap<String, Vec<u64>>,
+    /// The number of page blocks, by migrate type.
+    pub page_blocks: BTreeMap<String, u64>,
+}
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct PageTypeInfo {
+    /// The order of a page block, which is the unit of grouping pages by mobility.
+    pub page_block_order: u32,
+    pub pages_per_block:  u64,
+    pub zones:            Vec<PageTypeZone>,
+}
+
+#[inline]
+fn get_page_type_zone(
+    zones: &mut Vec<PageTypeZone>,
+    node: usize,
+    zone: String,
+) -> &mut PageTypeZone {
+    match zones.iter().position(|e| e.node == node && e.zone == zone) {
+        Some(index) => &mut zones[index],
+        None => {
+            zones.push(PageTypeZone {
+                node,
+                zone,
+                ..PageTypeZone::default()
+            });
+
+            zones.last_mut().unwrap()
+        },
+    }
+}
+
+/// Get free blocks and page blocks grouped by migrate types by reading a file in the format of `/proc/pagetypeinfo`.
+pub fn get_page_type_info_from_path<P: AsRef<Path>>(path: P) -> Result<PageTypeInfo, ScannerError> {
+    let mut sc: ScannerAscii<_, U1024> = ScannerAscii::scan_path2(path)?;
+
+    let mut page_type_info = PageTypeInfo::default();
+
+    enum Section {
+        FreeBlocks,
+        /// With the migrate types in the header.
+        PageBlocks(Vec<String>),
+        /// Such as `Number of mixed blocks` with `CONFIG_PAGE_OWNER`.
+        Other,
+    }
+
+    let mut section = Section::FreeBlocks;
+
+    while let Some(line) = sc.next_line_raw()? {
+        let line = unsafe { from_utf8_unchecked(&line) };
+
+        if let Some(value) = line.strip_prefix("Page block order:") {
+            page_type_info.page_block_order = value.trim().parse()?;
+        } else if let Some(value) = line.strip_prefix("Pages per block:") {
+            page_type_info.pages_per_block = value.trim().parse()?;
+        } else if let Some(types) = line.strip_prefix("Number of blocks type") {
+            section =
+                Section::PageBlocks(types.split_ascii_whitespace().map(String::from).collect());
+        } else if line.starts_with("Number of") {
+            section = Section::Other;
+        } else if line.starts_with("Node") {
+            let mut tokens = line.split_ascii_whitespace();
+
+            match &section {
+                Section::FreeBlocks => {
+                    let (node, zone) = parse_node_zone(&mut tokens)?;
+
+                    // `type    Unmovable      0      0 ...`
+                    if tokens.next() != Some("type") {
+                        return Err(ErrorKind::InvalidData.into());
+                    }
+
+                    let migrate_type = tokens.next().ok_or(ErrorKind::UnexpectedEof)?;
+
+                    let free_blocks =
+                        tokens.map(|count| count.parse()).collect::<Result<_, _>>()?;
+
+                    get_page_type_zone(&mut page_type_info.zones, node, zone)
+                        .free_blocks
+                        .insert(String::from(migrate_type), free_blocks);
+                },
+                Section::PageBlocks(block_types) => {
+                    let (node, zone) = parse_node_zone(&mut tokens)?;
+
+                    let page_type_zone = get_page_type_zone(&mut page_type_info.zones, node, zone);
+
+                    for migrate_type in block_types.iter() {
+                        let count = tokens.next().ok_or(ErrorKind::UnexpectedEof)?.parse()?;
+
+                        page_type_zone.page_blocks.insert(migrate_type.clone(), count);
+                    }
+                },
+                Section::Other => (),
+            }
+        }
+    }
+
+    Ok(page_type_info)
+}
+
+/// Get free blocks and page blocks grouped by migrate types by reading the `/proc/pagetypeinfo` file. Since Linux 5.7, the file can only be read by root.
+///
+/// ```rust,no_run
+/// use mprober_lib::memory;
+///
+/// let page_type_info = memory::get_page_type_info().unwrap();
+///
+/// for zone in page_type_info.zones {
+///     println!("node{} {}: {:?}", zone.node, zone.zone, zone.page_blocks);
+/// }
+/// ```
+#[inline]
+pub fn get_page_type_info() -> Result<PageTypeInfo, ScannerError> {
+    get_page_type_info_from_path("/proc/pagetypeinfo")
+}
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct SlabCache {
+    pub name:             String,
+    /// (`active_objs`)
+    pub active_objects:   u64,
+    /// (`num_objs`)
+    pub objects:          u64,
+    /// The size of an object, in bytes. (`objsize`)
+    pub object_size:      u64,
+    /// (`objperslab`)
+    pub objects_per_slab: u64,
+    /// (`pagesperslab`)
+    pub pages_per_slab:   u64,
+    /// (`active_slabs`)
+    pub active_slabs:     u64,
+    /// (`num_slabs`)
+    pub slabs:            u64,
+    /// The memory occupied by the slabs of this cache, in bytes.
+    pub size:             u64,
+}
+
+impl SlabCache {
+    /// The memory occupied by active objects, in bytes.
+    #[inline]
+    pub fn get_active_size(&self) -> u64 {
+        self.active_objects * self.object_size
+    }
+}
+
+/// Get slab caches by reading a file in the format of `/proc/slabinfo`.
+pub fn get_slab_caches_from_path<P: AsRef<Path>>(path: P) -> Result<Vec<SlabCache>, ScannerError> {
+    let mut sc: ScannerAscii<_, U256> = ScannerAscii::scan_path2(path)?;
+
+    // `slabinfo - version: 2.1` and the `# name ...` line
+    sc.drop_next_line()?.ok_or(ErrorKind::UnexpectedEof)?;
+    sc.drop_next_line()?.ok_or(ErrorKind::UnexpectedEof)?;
+
+    let page_size = get_page_size() as u64;
+
+    let mut slab_caches = Vec::new();
+
+    while let Some(line) = sc.next_line_raw()? {
+        let line = unsafe { from_utf8_unchecked(&line) };
+
+        let mut tokens = line.split_ascii_whitespace();
+
+        let name = match tokens.next() {
+            Some(name) => String::from(name),
+            None => continue,
+        };
+
+        let mut next_u64 = || -> Result<u64, ScannerError> {
+            Ok(tokens.next().ok_or(ErrorKind::UnexpectedEof)?.parse()?)
+        };
+
+        let active_objects = next_u64()?;
+        let objects = next_u64()?;
+        let object_size = next_u64()?;
+        let objects_per_slab = next_u64()?;
+        let pages_per_slab = next_u64()?;
+
+        // `: tunables <limit> <batchcount> <sharedfactor> : slabdata <active_slabs> <num_slabs> <sharedavail>`
+        if !tokens.any(|token| token == "slabdata") {
+            return Err(ErrorKind::InvalidData.into());
+        }
+
+        let mut next_u64 = || -> Result<u64, ScannerError> {
+            Ok(tokens.next().ok_or(ErrorKind::UnexpectedEof)?.parse()?)
+        };
+
+        let active_slabs = next_u64()?;
+        let slabs = next_u64()?;
+
+        slab_caches.push(SlabCache {
+            name,
+            active_objects,
+            objects,
+            object_size,
+            objects_per_slab,
+            pages_per_slab,
+            active_slabs,
+            slabs,
+            size: slabs * pages_per_slab * page_size,
+        });
+    }
+
+    Ok(slab_caches)
+}
+
+/// Get slab caches by reading the `/proc/slabinfo` file. The file can only be read by root.
+///
+/// ```rust,no_run
+/// use mprober_lib::memory;
+///
+/// let slab_caches = memory::get_slab_caches().unwrap();
+///
+/// println!("{}", slab_caches.len());
+/// ```
+#[inline]
+pub fn get_slab_caches() -> Result<Vec<SlabCache>, ScannerError> {
+    get_slab_caches_from_path("/proc/slabinfo")
+}
+
+/// Get the `n` slab caches which occupy the most memory by reading a file in the format of `/proc/slabinfo`, sorted by `size` in descending order.
+pub fn get_top_slab_caches_from_path<P: AsRef<Path>>(
+    path: P,
+    n: usize,
+) -> Result<Vec<SlabCache>, ScannerError> {
+    let mut slab_caches = get_slab_caches_from_path(path)?;
+
+    slab_caches.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.name.cmp(&b.name)));
+    slab_caches.truncate(n);
+
+    Ok(slab_caches)
+}
+
+/// Get the `n` slab caches which occupy the most memory by reading the `/proc/slabinfo` file, sorted by `size` in descending order. The file can only be read by root.
+///
+/// ```rust,no_run
+/// use mprober_lib::memory;
+///
+/// let slab_caches = memory::get_top_slab_caches(10).unwrap();
+///
+/// for slab_cache in slab_caches {
+///     println!("{}: {}", slab_cache.name, slab_cache.size);
+/// }
+/// ```
+#[inline]
+pub fn get_top_slab_caches(n: usize) -> Result<Vec<SlabCache>, ScannerError> {
+    get_top_slab_caches_from_path("/proc/slabinfo", n)
+}
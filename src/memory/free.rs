@@ -0,0 +1,110 @@
+use std::{io::ErrorKind, path::Path};
+
+use crate::{
+    memory::{get_mem_info_from_path, MemInfo},
+    scanner_rust::ScannerError,
+};
+
+#[derive(Default, Debug, Clone)]
+pub struct Mem {
+    pub total:     usize,
+    /// total - free - buffers - total_cached; total_cached = cached + s_reclaimable (saturating at `0`)
+    pub used:      usize,
+    pub free:      usize,
+    pub shared:    usize,
+    pub buffers:   usize,
+    pub cache:     usize,
+    pub available: usize,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct Swap {
+    pub total: usize,
+    /// swap_total - swap_free - swap_cached (saturating at `0`)
+    pub used:  usize,
+    pub free:  usize,
+    pub cache: usize,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct Free {
+    pub mem:  Mem,
+    pub swap: Swap,
+}
+
+impl TryFrom<&MemInfo> for Free {
+    type Error = ScannerError;
+
+    /// `MemTotal` and `MemFree` are required. Other missing fields fall back as follows.
+    ///
+    /// * `MemAvailable` (before Linux 3.14): estimated as `free + buffers + cache`, not exceeding `total`.
+    /// * `SReclaimable`: `Slab - SUnreclaim`, or `0` if either is missing.
+    /// * `Buffers`, `Cached`, `Shmem` and the swap fields: `0`.
+    fn try_from(mem_info: &MemInfo) -> Result<Self, Self::Error> {
+        let total = mem_info.mem_total.ok_or(ErrorKind::UnexpectedEof)? as usize;
+        let free = mem_info.mem_free.ok_or(ErrorKind::UnexpectedEof)? as usize;
+        let buffers = mem_info.buffers.unwrap_or(0) as usize;
+        let cached = mem_info.cached.unwrap_or(0) as usize;
+        let shmem = mem_info.shmem.unwrap_or(0) as usize;
+        let swap_cached = mem_info.swap_cached.unwrap_or(0) as usize;
+        let swap_total = mem_info.swap_total.unwrap_or(0) as usize;
+        let swap_free = mem_info.swap_free.unwrap_or(0) as usize;
+
+        let s_reclaimable = match mem_info.s_reclaimable {
+            Some(s_reclaimable) => s_reclaimable as usize,
+            None => match (mem_info.slab, mem_info.s_unreclaim) {
+                (Some(slab), Some(s_unreclaim)) => slab.saturating_sub(s_unreclaim) as usize,
+                _ => 0,
+            },
+        };
+
+        let total_cached = cached + s_reclaimable;
+
+        let available = match mem_info.mem_available {
+            Some(available) => available as usize,
+            None => (free + buffers + total_cached).min(total),
+        };
+
+        let mem = Mem {
+            total,
+            used: total.saturating_sub(free).saturating_sub(buffers).saturating_sub(total_cached),
+            free,
+            shared: shmem,
+            buffers,
+            cache: total_cached,
+            available,
+        };
+
+        let swap = Swap {
+            total: swap_total,
+            used:  swap_total.saturating_sub(swap_free).saturating_sub(swap_cached),
+            free:  swap_free,
+            cache: swap_cached,
+        };
+
+        Ok(Free {
+            mem,
+            swap,
+        })
+    }
+}
+
+/// Get memory information like the `free` command by reading a file in the format of `/proc/meminfo`.
+#[inline]
+pub fn free_from_path<P: AsRef<Path>>(path: P) -> Result<Free, ScannerError> {
+    Free::try_from(&get_mem_info_from_path(path)?)
+}
+
+/// Get memory information like the `free` command by reading the `/proc/meminfo` file. See the `TryFrom<&MemInfo>` implementation of `Free` for how missing fields are handled.
+///
+/// ```rust
+/// use mprober_lib::memory;
+///
+/// let free = memory::free().unwrap();
+///
+/// println!("{free:#?}");
+/// ```
+#[inline]
+pub fn free() -> Result<Free, ScannerError> {
+    free_from_path("/proc/meminfo")
+}
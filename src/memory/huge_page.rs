@@ -0,0 +1,240 @@
+use std::{io::ErrorKind, path::Path, time::Duration};
+
+use crate::{
+    functions::{read_one_value, read_selected_value},
+    memory::{get_mem_info_from_path, MemInfo},
+    scanner_rust::ScannerError,
+};
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct HugePagePool {
+    /// The size of a huge page in this pool, in bytes.
+    pub size:       u64,
+    /// The number of huge pages in this pool. (`nr_hugepages`)
+    pub total:      u64,
+    /// The number of huge pages which are not allocated yet. (`free_hugepages`)
+    pub free:       u64,
+    /// The number of huge pages which are committed to be allocated but not allocated yet. (`resv_hugepages`)
+    pub reserved:   u64,
+    /// The number of huge pages allocated above `total` by overcommitting. (`surplus_hugepages`)
+    pub surplus:    u64,
+    /// The maximum number of surplus huge pages. (`nr_overcommit_hugepages`)
+    pub overcommit: u64,
+}
+
+/// Information of the HugeTLB pages. The numbers are counts of huge pages, not in bytes.
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct HugePages {
+    /// The number of huge pages in the pool of the default size. (`HugePages_Total`)
+    pub total:    u64,
+    /// (`HugePages_Free`)
+    pub free:     u64,
+    /// (`HugePages_Rsvd`)
+    pub reserved: u64,
+    /// (`HugePages_Surp`)
+    pub surplus:  u64,
+    /// The default huge page size, in bytes. (`Hugepagesize`)
+    pub size:     u64,
+    /// The memory consumed by huge pages of all sizes, in bytes. Since Linux 4.16. (`Hugetlb`)
+    pub hugetlb:  Option<u64>,
+    /// The pools of each supported huge page size, sorted by the size.
+    pub pools:    Vec<HugePagePool>,
+}
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct Khugepaged {
+    /// Whether khugepaged may do direct compaction to allocate huge pages. (`defrag`)
+    pub defrag:          bool,
+    /// The number of pages to scan at each pass. (`pages_to_scan`)
+    pub pages_to_scan:   u64,
+    /// The time to sleep between passes. (`scan_sleep_millisecs`)
+    pub scan_sleep:      Duration,
+    /// The time to wait after a huge page allocation failure. (`alloc_sleep_millisecs`)
+    pub alloc_sleep:     Duration,
+    /// The number of huge pages which khugepaged has collapsed. (`pages_collapsed`)
+    pub pages_collapsed: u64,
+    /// The number of times khugepaged has scanned all memory. (`full_scans`)
+    pub full_scans:      u64,
+    /// The maximum number of unmapped pages in a range which can be collapsed. (`max_ptes_none`)
+    pub max_ptes_none:   u64,
+    /// The maximum number of swapped out pages in a range which can be collapsed. (`max_ptes_swap`)
+    pub max_ptes_swap:   u64,
+    /// The maximum number of shared pages in a range which can be collapsed. Since Linux 5.8. (`max_ptes_shared`)
+    pub max_ptes_shared: Option<u64>,
+}
+
+/// Settings of transparent huge pages. The modes are the selected options, such as `always`, `madvise` or `never`.
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct TransparentHugePage {
+    /// (`enabled`)
+    pub enabled:       String,
+    /// (`defrag`)
+    pub defrag:        String,
+    /// The mode for tmpfs/shmem mappings. (`shmem_enabled`)
+    pub shmem_enabled: Option<String>,
+    /// The size of a PMD-mapped transparent huge page, in bytes. Since Linux 4.20. (`hpage_pmd_size`)
+    pub pmd_size:      Option<u64>,
+    /// Whether the huge zero page is used for read faults. (`use_zero_page`)
+    pub use_zero_page: Option<bool>,
+    pub khugepaged:    Khugepaged,
+}
+
+#[inline]
+fn read_u64<P: AsRef<Path>>(path: P) -> Result<Option<u64>, ScannerError> {
+    match read_one_value(path)? {
+        Some(value) => Ok(Some(value.parse()?)),
+        None => Ok(None),
+    }
+}
+
+#[inline]
+fn read_required_u64<P: AsRef<Path>>(path: P) -> Result<u64, ScannerError> {
+    Ok(read_u64(path)?.ok_or(ErrorKind::NotFound)?)
+}
+
+/// Get huge page pools by reading a folder in the format of `/sys/kernel/mm/hugepages`. If the folder does not exist, it returns an empty vector.
+fn get_huge_page_pools<P: AsRef<Path>>(
+    hugepages_path: P,
+) -> Result<Vec<HugePagePool>, ScannerError> {
+    let dir = match hugepages_path.as_ref().read_dir() {
+        Ok(dir) => dir,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut pools = Vec::new();
+
+    for dir_entry in dir {
+        let dir_entry = dir_entry?;
+
+        let file_name = dir_entry.file_name();
+
+        // `hugepages-2048kB`
+        let size = match file_name
+            .to_str()
+            .and_then(|file_name| file_name.strip_prefix("hugepages-")?.strip_suffix("kB"))
+        {
+            Some(size) => size.parse::<u64>()? * 1024,
+            None => continue,
+        };
+
+        let path = dir_entry.path();
+
+        pools.push(HugePagePool {
+            size,
+            total: read_required_u64(path.join("nr_hugepages"))?,
+            free: read_required_u64(path.join("free_hugepages"))?,
+            reserved: read_required_u64(path.join("resv_hugepages"))?,
+            surplus: read_required_u64(path.join("surplus_hugepages"))?,
+            overcommit: read_required_u64(path.join("nr_overcommit_hugepages"))?,
+        });
+    }
+
+    pools.sort_unstable_by_key(|pool| pool.size);
+
+    Ok(pools)
+}
+
+/// Get the HugeTLB page information by reading a file in the format of `/proc/meminfo` and a folder in the format of `/sys/kernel/mm/hugepages`.
+pub fn get_huge_pages_from_paths<P: AsRef<Path>, HP: AsRef<Path>>(
+    meminfo_path: P,
+    hugepages_path: HP,
+) -> Result<HugePages, ScannerError> {
+    let MemInfo {
+        huge_pages_total,
+        huge_pages_free,
+        huge_pages_rsvd,
+        huge_pages_surp,
+        hugepagesize,
+        hugetlb,
+        ..
+    } = get_mem_info_from_path(meminfo_path)?;
+
+    // the kernel does not provide these fields without `CONFIG_HUGETLB_PAGE`
+    Ok(HugePages {
+        total: huge_pages_total.unwrap_or(0),
+        free: huge_pages_free.unwrap_or(0),
+        reserved: huge_pages_rsvd.unwrap_or(0),
+        surplus: huge_pages_surp.unwrap_or(0),
+        size: hugepagesize.unwrap_or(0),
+        hugetlb,
+        pools: get_huge_page_pools(hugepages_path)?,
+    })
+}
+
+/// Get the HugeTLB page information by reading the `/proc/meminfo` file and the `/sys/kernel/mm/hugepages` folder.
+///
+/// ```rust
+/// use mprober_lib::memory;
+///
+/// let huge_pages = memory::get_huge_pages().unwrap();
+///
+/// println!("{} / {}", huge_pages.free, huge_pages.total);
+///
+/// for pool in huge_pages.pools {
+///     println!("{}: {} / {}", pool.size, pool.free, pool.total);
+/// }
+/// ```
+#[inline]
+pub fn get_huge_pages() -> Result<HugePages, ScannerError> {
+    get_huge_pages_from_paths("/proc/meminfo", "/sys/kernel/mm/hugepages")
+}
+
+/// Get settings of transparent huge pages and khugepaged by reading a folder in the format of `/sys/kernel/mm/transparent_hugepage`. If the folder does not exist, it returns `None`.
+pub fn get_transparent_huge_page_from_path<P: AsRef<Path>>(
+    thp_path: P,
+) -> Result<Option<TransparentHugePage>, ScannerError> {
+    let thp_path = thp_path.as_ref();
+
+    let enabled = match read_selected_value(thp_path.join("enabled"))? {
+        Some(enabled) => enabled,
+        None => return Ok(None),
+    };
+
+    let defrag = read_selected_value(thp_path.join("defrag"))?.ok_or(ErrorKind::InvalidData)?;
+    let shmem_enabled = read_selected_value(thp_path.join("shmem_enabled"))?;
+    let pmd_size = read_u64(thp_path.join("hpage_pmd_size"))?;
+    let use_zero_page = read_u64(thp_path.join("use_zero_page"))?.map(|v| v != 0);
+
+    let khugepaged_path = thp_path.join("khugepaged");
+
+    let khugepaged = Khugepaged {
+        defrag:          read_required_u64(khugepaged_path.join("defrag"))? != 0,
+        pages_to_scan:   read_required_u64(khugepaged_path.join("pages_to_scan"))?,
+        scan_sleep:      Duration::from_millis(read_required_u64(
+            khugepaged_path.join("scan_sleep_millisecs"),
+        )?),
+        alloc_sleep:     Duration::from_millis(read_required_u64(
+            khugepaged_path.join("alloc_sleep_millisecs"),
+        )?),
+        pages_collapsed: read_required_u64(khugepaged_path.join("pages_collapsed"))?,
+        full_scans:      read_required_u64(khugepaged_path.join("full_scans"))?,
+        max_ptes_none:   read_required_u64(khugepaged_path.join("max_ptes_none"))?,
+        max_ptes_swap:   read_required_u64(khugepaged_path.join("max_ptes_swap"))?,
+        max_ptes_shared: read_u64(khugepaged_path.join("max_ptes_shared"))?,
+    };
+
+    Ok(Some(TransparentHugePage {
+        enabled,
+        defrag,
+        shmem_enabled,
+        pmd_size,
+        use_zero_page,
+        khugepaged,
+    }))
+}
+
+/// Get settings of transparent huge pages and khugepaged by reading the `/sys/kernel/mm/transparent_hugepage` folder. If the kernel does not support transparent huge pages, it returns `None`.
+///
+/// ```rust
+/// use mprober_lib::memory;
+///
+/// if let Some(thp) = memory::get_transparent_huge_page().unwrap() {
+///     println!("enabled: {}, defrag: {}", thp.enabled, thp.defrag);
+///     println!("collapsed: {}", thp.khugepaged.pages_collapsed);
+/// }
+/// ```
+#[inline]
+pub fn get_transparent_huge_page() -> Result<Option<TransparentHugePage>, ScannerError> {
+    get_transparent_huge_page_from_path("/sys/kernel/mm/transparent_hugepage")
+}
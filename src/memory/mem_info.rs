@@ -0,0 +1,273 @@
+use std::{collections::BTreeMap, io::ErrorKind, path::Path, str::from_utf8_unchecked};
+
+use crate::scanner_rust::{generic_array::typenum::U768, ScannerAscii, ScannerError};
+
+/// Fields of the `/proc/meminfo` file. Sizes are in bytes. A field is `None` if the kernel does not provide it.
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct MemInfo {
+    /// (`MemTotal`)
+    pub mem_total:          Option<u64>,
+    /// (`MemFree`)
+    pub mem_free:           Option<u64>,
+    /// Since Linux 3.14. (`MemAvailable`)
+    pub mem_available:      Option<u64>,
+    /// Only in the per-node `meminfo` files. (`MemUsed`)
+    pub mem_used:           Option<u64>,
+    /// (`Buffers`)
+    pub buffers:            Option<u64>,
+    /// (`Cached`)
+    pub cached:             Option<u64>,
+    /// (`SwapCached`)
+    pub swap_cached:        Option<u64>,
+    /// (`Active`)
+    pub active:             Option<u64>,
+    /// (`Inactive`)
+    pub inactive:           Option<u64>,
+    /// (`Active(anon)`)
+    pub active_anon:        Option<u64>,
+    /// (`Inactive(anon)`)
+    pub inactive_anon:      Option<u64>,
+    /// (`Active(file)`)
+    pub active_file:        Option<u64>,
+    /// (`Inactive(file)`)
+    pub inactive_file:      Option<u64>,
+    /// (`Unevictable`)
+    pub unevictable:        Option<u64>,
+    /// (`Mlocked`)
+    pub mlocked:            Option<u64>,
+    /// Only on 32-bit kernels with `CONFIG_HIGHMEM`. (`HighTotal`)
+    pub high_total:         Option<u64>,
+    /// Only on 32-bit kernels with `CONFIG_HIGHMEM`. (`HighFree`)
+    pub high_free:          Option<u64>,
+    /// Only on 32-bit kernels with `CONFIG_HIGHMEM`. (`LowTotal`)
+    pub low_total:          Option<u64>,
+    /// Only on 32-bit kernels with `CONFIG_HIGHMEM`. (`LowFree`)
+    pub low_free:           Option<u64>,
+    /// Only on kernels without MMU. (`MmapCopy`)
+    pub mmap_copy:          Option<u64>,
+    /// (`SwapTotal`)
+    pub swap_total:         Option<u64>,
+    /// (`SwapFree`)
+    pub swap_free:          Option<u64>,
+    /// (`Zswap`)
+    pub zswap:              Option<u64>,
+    /// (`Zswapped`)
+    pub zswapped:           Option<u64>,
+    /// (`Dirty`)
+    pub dirty:              Option<u64>,
+    /// (`Writeback`)
+    pub writeback:          Option<u64>,
+    /// (`AnonPages`)
+    pub anon_pages:         Option<u64>,
+    /// (`Mapped`)
+    pub mapped:             Option<u64>,
+    /// (`Shmem`)
+    pub shmem:              Option<u64>,
+    /// (`KReclaimable`)
+    pub k_reclaimable:      Option<u64>,
+    /// (`Slab`)
+    pub slab:               Option<u64>,
+    /// (`SReclaimable`)
+    pub s_reclaimable:      Option<u64>,
+    /// (`SUnreclaim`)
+    pub s_unreclaim:        Option<u64>,
+    /// (`KernelStack`)
+    pub kernel_stack:       Option<u64>,
+    /// (`ShadowCallStack`)
+    pub shadow_call_stack:  Option<u64>,
+    /// (`PageTables`)
+    pub page_tables:        Option<u64>,
+    /// (`SecPageTables`)
+    pub sec_page_tables:    Option<u64>,
+    /// (`NFS_Unstable`)
+    pub nfs_unstable:       Option<u64>,
+    /// (`Bounce`)
+    pub bounce:             Option<u64>,
+    /// (`WritebackTmp`)
+    pub writeback_tmp:      Option<u64>,
+    /// (`CommitLimit`)
+    pub commit_limit:       Option<u64>,
+    /// (`Committed_AS`)
+    pub committed_as:       Option<u64>,
+    /// (`VmallocTotal`)
+    pub vmalloc_total:      Option<u64>,
+    /// (`VmallocUsed`)
+    pub vmalloc_used:       Option<u64>,
+    /// (`VmallocChunk`)
+    pub vmalloc_chunk:      Option<u64>,
+    /// (`Percpu`)
+    pub percpu:             Option<u64>,
+    /// (`HardwareCorrupted`)
+    pub hardware_corrupted: Option<u64>,
+    /// (`AnonHugePages`)
+    pub anon_huge_pages:    Option<u64>,
+    /// (`ShmemHugePages`)
+    pub shmem_huge_pages:   Option<u64>,
+    /// (`ShmemPmdMapped`)
+    pub shmem_pmd_mapped:   Option<u64>,
+    /// (`FileHugePages`)
+    pub file_huge_pages:    Option<u64>,
+    /// (`FilePmdMapped`)
+    pub file_pmd_mapped:    Option<u64>,
+    /// (`CmaTotal`)
+    pub cma_total:          Option<u64>,
+    /// (`CmaFree`)
+    pub cma_free:           Option<u64>,
+    /// (`Unaccepted`)
+    pub unaccepted:         Option<u64>,
+    /// (`Balloon`)
+    pub balloon:            Option<u64>,
+    /// The number of huge pages, not in bytes. (`HugePages_Total`)
+    pub huge_pages_total:   Option<u64>,
+    /// The number of huge pages, not in bytes. (`HugePages_Free`)
+    pub huge_pages_free:    Option<u64>,
+    /// The number of huge pages, not in bytes. (`HugePages_Rsvd`)
+    pub huge_pages_rsvd:    Option<u64>,
+    /// The number of huge pages, not in bytes. (`HugePages_Surp`)
+    pub huge_pages_surp:    Option<u64>,
+    /// (`Hugepagesize`)
+    pub hugepagesize:       Option<u64>,
+    /// (`Hugetlb`)
+    pub hugetlb:            Option<u64>,
+    /// (`DirectMap4k`)
+    pub direct_map_4k:      Option<u64>,
+    /// (`DirectMap2M`)
+    pub direct_map_2m:      Option<u64>,
+    /// (`DirectMap4M`)
+    pub direct_map_4m:      Option<u64>,
+    /// (`DirectMap1G`)
+    pub direct_map_1g:      Option<u64>,
+    /// Fields which are not known by this crate, such as new or vendor-specific ones. The values are in bytes if they have the `kB` unit.
+    pub others:             BTreeMap<String, u64>,
+}
+
+impl MemInfo {
+    /// Set a field by its label in the `/proc/meminfo` file.
+    pub(crate) fn set_field(&mut self, label: &str, value: u64) {
+        let field = match label {
+            "MemTotal" => &mut self.mem_total,
+            "MemFree" => &mut self.mem_free,
+            "MemAvailable" => &mut self.mem_available,
+            "MemUsed" => &mut self.mem_used,
+            "Buffers" => &mut self.buffers,
+            "Cached" => &mut self.cached,
+            "SwapCached" => &mut self.swap_cached,
+            "Active" => &mut self.active,
+            "Inactive" => &mut self.inactive,
+            "Active(anon)" => &mut self.active_anon,
+            "Inactive(anon)" => &mut self.inactive_anon,
+            "Active(file)" => &mut self.active_file,
+            "Inactive(file)" => &mut self.inactive_file,
+            "Unevictable" => &mut self.unevictable,
+            "Mlocked" => &mut self.mlocked,
+            "HighTotal" => &mut self.high_total,
+            "HighFree" => &mut self.high_free,
+            "LowTotal" => &mut self.low_total,
+            "LowFree" => &mut self.low_free,
+            "MmapCopy" => &mut self.mmap_copy,
+            "SwapTotal" => &mut self.swap_total,
+            "SwapFree" => &mut self.swap_free,
+            "Zswap" => &mut self.zswap,
+            "Zswapped" => &mut self.zswapped,
+            "Dirty" => &mut self.dirty,
+            "Writeback" => &mut self.writeback,
+            "AnonPages" => &mut self.anon_pages,
+            "Mapped" => &mut self.mapped,
+            "Shmem" => &mut self.shmem,
+            "KReclaimable" => &mut self.k_reclaimable,
+            "Slab" => &mut self.slab,
+            "SReclaimable" => &mut self.s_reclaimable,
+            "SUnreclaim" => &mut self.s_unreclaim,
+            "KernelStack" => &mut self.kernel_stack,
+            "ShadowCallStack" => &mut self.shadow_call_stack,
+            "PageTables" => &mut self.page_tables,
+            "SecPageTables" => &mut self.sec_page_tables,
+            "NFS_Unstable" => &mut self.nfs_unstable,
+            "Bounce" => &mut self.bounce,
+            "WritebackTmp" => &mut self.writeback_tmp,
+            "CommitLimit" => &mut self.commit_limit,
+            "Committed_AS" => &mut self.committed_as,
+            "VmallocTotal" => &mut self.vmalloc_total,
+            "VmallocUsed" => &mut self.vmalloc_used,
+            "VmallocChunk" => &mut self.vmalloc_chunk,
+            "Percpu" => &mut self.percpu,
+            "HardwareCorrupted" => &mut self.hardware_corrupted,
+            "AnonHugePages" => &mut self.anon_huge_pages,
+            "ShmemHugePages" => &mut self.shmem_huge_pages,
+            "ShmemPmdMapped" => &mut self.shmem_pmd_mapped,
+            "FileHugePages" => &mut self.file_huge_pages,
+            "FilePmdMapped" => &mut self.file_pmd_mapped,
+            "CmaTotal" => &mut self.cma_total,
+            "CmaFree" => &mut self.cma_free,
+            "Unaccepted" => &mut self.unaccepted,
+            "Balloon" => &mut self.balloon,
+            "HugePages_Total" => &mut self.huge_pages_total,
+            "HugePages_Free" => &mut self.huge_pages_free,
+            "HugePages_Rsvd" => &mut self.huge_pages_rsvd,
+            "HugePages_Surp" => &mut self.huge_pages_surp,
+            "Hugepagesize" => &mut self.hugepagesize,
+            "Hugetlb" => &mut self.hugetlb,
+            "DirectMap4k" => &mut self.direct_map_4k,
+            "DirectMap2M" => &mut self.direct_map_2m,
+            "DirectMap4M" => &mut self.direct_map_4m,
+            "DirectMap1G" => &mut self.direct_map_1g,
+            _ => {
+                self.others.insert(String::from(label), value);
+
+                return;
+            },
+        };
+
+        *field = Some(value);
+    }
+}
+
+/// Parse a line like `Active(anon):     123 kB` into a `MemInfo` instance.
+pub(crate) fn parse_mem_info_line(mem_info: &mut MemInfo, line: &str) -> Result<(), ScannerError> {
+    let (label, value) = line.split_once(':').ok_or(ErrorKind::InvalidData)?;
+
+    let mut tokens = value.split_ascii_whitespace();
+
+    let value: u64 = tokens.next().ok_or(ErrorKind::UnexpectedEof)?.parse()?;
+
+    let value = match tokens.next() {
+        Some("kB") => value * 1024,
+        Some(_) => return Err(ErrorKind::InvalidData.into()),
+        None => value,
+    };
+
+    mem_info.set_field(label.trim(), value);
+
+    Ok(())
+}
+
+/// Get memory information by reading a file in the format of `/proc/meminfo`.
+pub fn get_mem_info_from_path<P: AsRef<Path>>(path: P) -> Result<MemInfo, ScannerError> {
+    let mut sc: ScannerAscii<_, U768> = ScannerAscii::scan_path2(path)?;
+
+    let mut mem_info = MemInfo::default();
+
+    while let Some(line) = sc.next_line_raw()? {
+        if line.is_empty() {
+            continue;
+        }
+
+        parse_mem_info_line(&mut mem_info, unsafe { from_utf8_unchecked(&line) })?;
+    }
+
+    Ok(mem_info)
+}
+
+/// Get memory information by reading the `/proc/meminfo` file.
+///
+/// ```rust
+/// use mprober_lib::memory;
+///
+/// let mem_info = memory::get_mem_info().unwrap();
+///
+/// println!("{:?}", mem_info.mem_available);
+/// ```
+#[inline]
+pub fn get_mem_info() -> Result<MemInfo, ScannerError> {
+    get_mem_info_from_path("/proc/meminfo")
+}
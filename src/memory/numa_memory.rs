@@ -0,0 +1,124 @@
+use std::{io::ErrorKind, path::Path, str::from_utf8_unchecked};
+
+use crate::{
+    cpu::get_numa_nodes_from_path,
+    memory::{parse_mem_info_line, MemInfo},
+    scanner_rust::{generic_array::typenum::U768, ScannerAscii, ScannerError},
+};
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct NUMAStat {
+    /// Allocations which were intended for this node and succeeded here.
+    pub numa_hit:       u64,
+    /// Allocations which were intended for another node but made on this node.
+    pub numa_miss:      u64,
+    /// Allocations which were intended for this node but made on another node.
+    pub numa_foreign:   u64,
+    /// Interleaved allocations which were intended for this node and succeeded here.
+    pub interleave_hit: u64,
+    /// Allocations made on this node while the process was running on it.
+    pub local_node:     u64,
+    /// Allocations made on this node while the process was running on another node.
+    pub other_node:     u64,
+}
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct NUMANodeMemory {
+    pub node:      usize,
+    /// CPUs on this node, the same as the `cpus` of `cpu::NUMANode`.
+    pub cpus:      Vec<usize>,
+    /// Only the fields which the kernel provides per node are `Some`.
+    pub mem_info:  MemInfo,
+    pub numa_stat: NUMAStat,
+}
+
+fn get_node_mem_info<P: AsRef<Path>>(path: P) -> Result<MemInfo, ScannerError> {
+    let mut sc: ScannerAscii<_, U768> = ScannerAscii::scan_path2(path)?;
+
+    let mut mem_info = MemInfo::default();
+
+    while let Some(line) = sc.next_line_raw()? {
+        if line.is_empty() {
+            continue;
+        }
+
+        // `Node 0 MemTotal:        5603064 kB`
+        let line = unsafe { from_utf8_unchecked(&line) }
+            .strip_prefix("Node ")
+            .and_then(|line| line.split_once(' '))
+            .map(|(_, line)| line)
+            .ok_or(ErrorKind::InvalidData)?;
+
+        parse_mem_info_line(&mut mem_info, line)?;
+    }
+
+    Ok(mem_info)
+}
+
+fn get_numa_stat<P: AsRef<Path>>(path: P) -> Result<NUMAStat, ScannerError> {
+    let mut sc: ScannerAscii<_, U768> = ScannerAscii::scan_path2(path)?;
+
+    let mut numa_stat = NUMAStat::default();
+
+    while let Some(name) = sc.next_raw()? {
+        let value = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+
+        match name.as_slice() {
+            b"numa_hit" => numa_stat.numa_hit = value,
+            b"numa_miss" => numa_stat.numa_miss = value,
+            b"numa_foreign" => numa_stat.numa_foreign = value,
+            b"interleave_hit" => numa_stat.interleave_hit = value,
+            b"local_node" => numa_stat.local_node = value,
+            b"other_node" => numa_stat.other_node = value,
+            _ => (),
+        }
+    }
+
+    Ok(numa_stat)
+}
+
+/// Get memory information of NUMA nodes by reading a folder in the format of `/sys/devices/system/node`.
+pub fn get_numa_nodes_memory_from_path<P: AsRef<Path>>(
+    node_path: P,
+) -> Result<Vec<NUMANodeMemory>, ScannerError> {
+    let node_path = node_path.as_ref();
+
+    let nodes = get_numa_nodes_from_path(node_path)?;
+
+    let mut nodes_memory = Vec::with_capacity(nodes.len());
+
+    for node in nodes {
+        let path = node_path.join(format!("node{}", node.node));
+
+        nodes_memory.push(NUMANodeMemory {
+            node:      node.node,
+            cpus:      node.cpus,
+            mem_info:  get_node_mem_info(path.join("meminfo"))?,
+            numa_stat: get_numa_stat(path.join("numastat"))?,
+        });
+    }
+
+    Ok(nodes_memory)
+}
+
+/// Get memory information of each NUMA node by reading the `meminfo` and `numastat` files in the `/sys/devices/system/node/nodeN` folders. The CPUs of each node are the same as `cpu::get_numa_nodes`. If the kernel does not support NUMA, it returns an empty vector.
+///
+/// ```rust
+/// use mprober_lib::memory;
+///
+/// let nodes_memory = memory::get_numa_nodes_memory().unwrap();
+///
+/// for node_memory in nodes_memory {
+///     println!(
+///         "node{} {:?}: {:?} free, {} misses",
+///         node_memory.node,
+///         node_memory.cpus,
+///         node_memory.mem_info.mem_free,
+///         node_memory.numa_stat.numa_miss
+///     );
+/// }
+/// ```
+#[inline]
+pub fn get_numa_nodes_memory() -> Result<Vec<NUMANodeMemory>, ScannerError> {
+    get_numa_nodes_memory_from_path("/sys/devices/system/node")
+}
@@ -0,0 +1,143 @@
+use std::{io::ErrorKind, path::Path, str::from_utf8_unchecked};
+
+use crate::{
+    memory::{get_zram_from_path, Zram},
+    scanner_rust::{generic_array::typenum::U256, ScannerAscii, ScannerError},
+};
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum SwapType {
+    #[default]
+    Partition,
+    File,
+}
+
+impl SwapType {
+    #[allow(clippy::should_implement_trait)]
+    #[inline]
+    pub fn from_str<S: AsRef<str>>(s: S) -> Option<SwapType> {
+        match s.as_ref() {
+            "partition" => Some(SwapType::Partition),
+            "file" => Some(SwapType::File),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SwapType::Partition => "partition",
+            SwapType::File => "file",
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct SwapDevice {
+    /// The path of the swap partition or the swap file.
+    pub filename:  String,
+    pub swap_type: SwapType,
+    /// In bytes.
+    pub size:      u64,
+    /// In bytes.
+    pub used:      u64,
+    /// Swap devices with higher priorities are used first.
+    pub priority:  i32,
+    /// The zram device information if this is a zram-backed swap.
+    pub zram:      Option<Zram>,
+}
+
+/// `/path/with\040space` -> `/path/with space`
+fn unescape_octal(s: &str) -> String {
+    let bytes = s.as_bytes();
+
+    let mut result = Vec::with_capacity(bytes.len());
+
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'\\'
+            && i + 3 < bytes.len()
+            && bytes[i + 1..i + 4].iter().all(|e| (b'0'..=b'7').contains(e))
+        {
+            let c = bytes[i + 1..i + 4].iter().fold(0u32, |c, e| c * 8 + (e - b'0') as u32);
+
+            result.push(c as u8);
+            i += 4;
+
+            continue;
+        }
+
+        result.push(bytes[i]);
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&result).into_owned()
+}
+
+/// Get swap devices by reading a file in the format of `/proc/swaps`, and zram devices from a folder in the format of `/sys/block`.
+pub fn get_swap_devices_from_paths<P: AsRef<Path>, BP: AsRef<Path>>(
+    swaps_path: P,
+    sys_block_path: BP,
+) -> Result<Vec<SwapDevice>, ScannerError> {
+    let sys_block_path = sys_block_path.as_ref();
+
+    let mut sc: ScannerAscii<_, U256> = ScannerAscii::scan_path2(swaps_path)?;
+
+    sc.drop_next_line()?.ok_or(ErrorKind::UnexpectedEof)?;
+
+    let mut swap_devices = Vec::new();
+
+    while let Some(filename) = sc.next_raw()? {
+        let filename = unescape_octal(unsafe { from_utf8_unchecked(&filename) });
+
+        let swap_type = sc.next_raw()?.ok_or(ErrorKind::UnexpectedEof)?;
+        let swap_type = SwapType::from_str(unsafe { from_utf8_unchecked(&swap_type) })
+            .ok_or(ErrorKind::InvalidData)?;
+
+        let size = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)? * 1024;
+        let used = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)? * 1024;
+        let priority = sc.next_i32()?.ok_or(ErrorKind::UnexpectedEof)?;
+
+        let zram = match filename.strip_prefix("/dev/") {
+            Some(device) if device.starts_with("zram") => {
+                get_zram_from_path(sys_block_path.join(device))?
+            },
+            _ => None,
+        };
+
+        swap_devices.push(SwapDevice {
+            filename,
+            swap_type,
+            size,
+            used,
+            priority,
+            zram,
+        });
+    }
+
+    Ok(swap_devices)
+}
+
+/// Get swap devices by reading the `/proc/swaps` file. For zram-backed swap, the zram device information is read from the `/sys/block/zramN` folder.
+///
+/// ```rust
+/// use mprober_lib::memory;
+///
+/// let swap_devices = memory::get_swap_devices().unwrap();
+///
+/// for swap_device in swap_devices {
+///     println!(
+///         "{}: {} / {}",
+///         swap_device.filename, swap_device.used, swap_device.size
+///     );
+///
+///     if let Some(zram) = swap_device.zram {
+///         println!("{:?}", zram.get_compression_ratio());
+///     }
+/// }
+/// ```
+#[inline]
+pub fn get_swap_devices() -> Result<Vec<SwapDevice>, ScannerError> {
+    get_swap_devices_from_paths("/proc/swaps", "/sys/block")
+}
@@ -0,0 +1,230 @@
+use std::{
+    collections::BTreeMap, io::ErrorKind, path::Path, str::from_utf8_unchecked, thread::sleep,
+    time::Duration,
+};
+
+use crate::scanner_rust::{generic_array::typenum::U768, ScannerAscii, ScannerError};
+
+#[derive(Default, Debug, Clone)]
+pub struct VMStatRate {
+    /// Kilobytes paged in from disk per second.
+    pub pgpgin:             f64,
+    /// Kilobytes paged out to disk per second.
+    pub pgpgout:            f64,
+    /// Pages swapped in per second.
+    pub pswpin:             f64,
+    /// Pages swapped out per second.
+    pub pswpout:            f64,
+    /// Page faults per second.
+    pub pgfault:            f64,
+    /// Major page faults (which need disk I/O) per second.
+    pub pgmajfault:         f64,
+    /// Pages scanned by page reclaim per second.
+    pub pgscan:             f64,
+    /// Pages reclaimed per second.
+    pub pgsteal:            f64,
+    /// Direct reclaim stalls per second.
+    pub allocstall:         f64,
+    /// Processes killed by the OOM killer per second.
+    pub oom_kill:           f64,
+    pub thp_fault_alloc:    f64,
+    pub thp_fault_fallback: f64,
+    pub thp_collapse_alloc: f64,
+    pub thp_split_page:     f64,
+    pub compact_stall:      f64,
+    /// Rates per second of all event counters. Gauges (the `nr_*` fields) are not included.
+    pub counters:           BTreeMap<String, f64>,
+}
+
+/// Virtual memory statistics of the `/proc/vmstat` file. A field is `None` if the kernel does not provide it. Except for `nr_*` gauges, the values are cumulative since boot.
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct VMStat {
+    /// Kilobytes paged in from disk.
+    pub pgpgin:             Option<u64>,
+    /// Kilobytes paged out to disk.
+    pub pgpgout:            Option<u64>,
+    /// Pages swapped in.
+    pub pswpin:             Option<u64>,
+    /// Pages swapped out.
+    pub pswpout:            Option<u64>,
+    /// Page faults.
+    pub pgfault:            Option<u64>,
+    /// Major page faults, which need disk I/O.
+    pub pgmajfault:         Option<u64>,
+    /// Pages scanned by page reclaim. (the sum of `pgscan_kswapd`, `pgscan_direct`, etc.)
+    pub pgscan:             Option<u64>,
+    /// Pages reclaimed. (the sum of `pgsteal_kswapd`, `pgsteal_direct`, etc.)
+    pub pgsteal:            Option<u64>,
+    /// Direct reclaim stalls. (the sum of `allocstall_*` since Linux 4.10)
+    pub allocstall:         Option<u64>,
+    /// Processes killed by the OOM killer. Since Linux 4.13.
+    pub oom_kill:           Option<u64>,
+    pub thp_fault_alloc:    Option<u64>,
+    pub thp_fault_fallback: Option<u64>,
+    pub thp_collapse_alloc: Option<u64>,
+    pub thp_split_page:     Option<u64>,
+    pub compact_stall:      Option<u64>,
+    /// All fields of the file.
+    pub counters:           BTreeMap<String, u64>,
+}
+
+impl VMStat {
+    /// Get a field by its name in the `/proc/vmstat` file.
+    #[inline]
+    pub fn get<S: AsRef<str>>(&self, name: S) -> Option<u64> {
+        self.counters.get(name.as_ref()).copied()
+    }
+
+    /// Calculate rates per second between two `VMStat` instances at different time.
+    ///
+    /// ```rust
+    /// use std::{thread::sleep, time::Duration};
+    ///
+    /// use mprober_lib::memory;
+    ///
+    /// let pre_vmstat = memory::get_vmstat().unwrap();
+    ///
+    /// let interval = Duration::from_millis(100);
+    ///
+    /// sleep(interval);
+    ///
+    /// let vmstat = memory::get_vmstat().unwrap();
+    ///
+    /// let vmstat_rate = pre_vmstat.compute_rate(&vmstat, interval);
+    ///
+    /// println!("Major page faults: {:.1}/s", vmstat_rate.pgmajfault);
+    /// println!("Swap-ins: {:.1}/s", vmstat_rate.pswpin);
+    /// ```
+    pub fn compute_rate(&self, vmstat_after_this: &VMStat, interval: Duration) -> VMStatRate {
+        let seconds = interval.as_secs_f64();
+
+        let rate = |before: Option<u64>, after: Option<u64>| match (before, after) {
+            (Some(before), Some(after)) => after.saturating_sub(before) as f64 / seconds,
+            _ => 0.0,
+        };
+
+        let counters = vmstat_after_this
+            .counters
+            .iter()
+            .filter(|(name, _)| !name.starts_with("nr_"))
+            .filter_map(|(name, after)| {
+                self.counters
+                    .get(name)
+                    .map(|before| (name.clone(), after.saturating_sub(*before) as f64 / seconds))
+            })
+            .collect();
+
+        let after = vmstat_after_this;
+
+        VMStatRate {
+            pgpgin: rate(self.pgpgin, after.pgpgin),
+            pgpgout: rate(self.pgpgout, after.pgpgout),
+            pswpin: rate(self.pswpin, after.pswpin),
+            pswpout: rate(self.pswpout, after.pswpout),
+            pgfault: rate(self.pgfault, after.pgfault),
+            pgmajfault: rate(self.pgmajfault, after.pgmajfault),
+            pgscan: rate(self.pgscan, after.pgscan),
+            pgsteal: rate(self.pgsteal, after.pgsteal),
+            allocstall: rate(self.allocstall, after.allocstall),
+            oom_kill: rate(self.oom_kill, after.oom_kill),
+            thp_fault_alloc: rate(self.thp_fault_alloc, after.thp_fault_alloc),
+            thp_fault_fallback: rate(self.thp_fault_fallback, after.thp_fault_fallback),
+            thp_collapse_alloc: rate(self.thp_collapse_alloc, after.thp_collapse_alloc),
+            thp_split_page: rate(self.thp_split_page, after.thp_split_page),
+            compact_stall: rate(self.compact_stall, after.compact_stall),
+            counters,
+        }
+    }
+}
+
+/// Sum the fields which match `f`. `None` if no field matches.
+#[inline]
+fn sum_counters<F: Fn(&str) -> bool>(counters: &BTreeMap<String, u64>, f: F) -> Option<u64> {
+    counters.iter().filter(|(name, _)| f(name)).map(|(_, value)| *value).reduce(|a, b| a + b)
+}
+
+/// Get virtual memory statistics by reading a file in the format of `/proc/vmstat`.
+pub fn get_vmstat_from_path<P: AsRef<Path>>(path: P) -> Result<VMStat, ScannerError> {
+    let mut sc: ScannerAscii<_, U768> = ScannerAscii::scan_path2(path)?;
+
+    let mut counters = BTreeMap::new();
+
+    while let Some(name) = sc.next_raw()? {
+        let value = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+
+        counters.insert(String::from(unsafe { from_utf8_unchecked(&name) }), value);
+    }
+
+    let get = |name: &str| counters.get(name).copied();
+
+    // `pgscan_anon`, `pgscan_file` and `pgsteal_anon`, `pgsteal_file` are breakdowns of the same pages, and `pgscan_direct_throttle` counts throttling events
+    let pgscan = sum_counters(&counters, |name| {
+        name.starts_with("pgscan_")
+            && !matches!(name, "pgscan_anon" | "pgscan_file" | "pgscan_direct_throttle")
+    });
+    let pgsteal = sum_counters(&counters, |name| {
+        name.starts_with("pgsteal_") && !matches!(name, "pgsteal_anon" | "pgsteal_file")
+    });
+    let allocstall =
+        sum_counters(&counters, |name| name == "allocstall" || name.starts_with("allocstall_"));
+
+    Ok(VMStat {
+        pgpgin: get("pgpgin"),
+        pgpgout: get("pgpgout"),
+        pswpin: get("pswpin"),
+        pswpout: get("pswpout"),
+        pgfault: get("pgfault"),
+        pgmajfault: get("pgmajfault"),
+        pgscan,
+        pgsteal,
+        allocstall,
+        oom_kill: get("oom_kill"),
+        thp_fault_alloc: get("thp_fault_alloc"),
+        thp_fault_fallback: get("thp_fault_fallback"),
+        thp_collapse_alloc: get("thp_collapse_alloc"),
+        thp_split_page: get("thp_split_page"),
+        compact_stall: get("compact_stall"),
+        counters,
+    })
+}
+
+/// Get virtual memory statistics by reading the `/proc/vmstat` file.
+///
+/// ```rust
+/// use mprober_lib::memory;
+///
+/// let vmstat = memory::get_vmstat().unwrap();
+///
+/// println!("{:?}", vmstat.pgmajfault);
+/// println!("{:?}", vmstat.get("nr_dirty"));
+/// ```
+#[inline]
+pub fn get_vmstat() -> Result<VMStat, ScannerError> {
+    get_vmstat_from_path("/proc/vmstat")
+}
+
+/// Get virtual memory statistics and their rates per second within a specific time interval by reading the `/proc/vmstat` file. It will cause the current thread to sleep.
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use mprober_lib::memory;
+///
+/// let (_vmstat, vmstat_rate) =
+///     memory::get_vmstat_with_rate(Duration::from_millis(100)).unwrap();
+///
+/// println!("Swap-ins: {:.1}/s", vmstat_rate.pswpin);
+/// println!("Swap-outs: {:.1}/s", vmstat_rate.pswpout);
+/// ```
+#[inline]
+pub fn get_vmstat_with_rate(interval: Duration) -> Result<(VMStat, VMStatRate), ScannerError> {
+    let pre_vmstat = get_vmstat()?;
+
+    sleep(interval);
+
+    let vmstat = get_vmstat()?;
+
+    let vmstat_rate = pre_vmstat.compute_rate(&vmstat, interval);
+
+    Ok((vmstat, vmstat_rate))
+}
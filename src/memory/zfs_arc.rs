@@ -0,0 +1,112 @@
+use std::{io::ErrorKind, path::Path};
+
+use crate::{
+    memory::{free_from_path, Free},
+    scanner_rust::{generic_array::typenum::U768, ScannerAscii, ScannerError},
+};
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct ZFSARC {
+    /// The current size of the ARC, in bytes. (`size`)
+    pub size:     u64,
+    /// The size the ARC is aiming for, in bytes. (`c`)
+    pub target:   u64,
+    /// The ARC does not shrink below this size, in bytes. (`c_min`)
+    pub min_size: u64,
+    /// The ARC does not grow above this size, in bytes. (`c_max`)
+    pub max_size: u64,
+}
+
+impl ZFSARC {
+    /// The part of the ARC which can be given back to the system under memory pressure, in bytes.
+    #[inline]
+    pub fn get_reclaimable_size(&self) -> u64 {
+        self.size.saturating_sub(self.min_size)
+    }
+}
+
+/// Get the ZFS ARC (Adaptive Replacement Cache) information by reading a file in the format of `/proc/spl/kstat/zfs/arcstats`. If the file does not exist, it returns `None`.
+pub fn get_zfs_arc_from_path<P: AsRef<Path>>(path: P) -> Result<Option<ZFSARC>, ScannerError> {
+    let mut sc: ScannerAscii<_, U768> = match ScannerAscii::scan_path2(path) {
+        Ok(sc) => sc,
+        Err(ScannerError::IOError(err)) if err.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+
+    // the kstat header and the `name type data` line
+    sc.drop_next_line()?.ok_or(ErrorKind::UnexpectedEof)?;
+    sc.drop_next_line()?.ok_or(ErrorKind::UnexpectedEof)?;
+
+    let mut zfs_arc = ZFSARC::default();
+
+    while let Some(name) = sc.next_raw()? {
+        let field = match name.as_slice() {
+            b"size" => &mut zfs_arc.size,
+            b"c" => &mut zfs_arc.target,
+            b"c_min" => &mut zfs_arc.min_size,
+            b"c_max" => &mut zfs_arc.max_size,
+            _ => {
+                sc.drop_next_line()?;
+
+                continue;
+            },
+        };
+
+        sc.drop_next()?.ok_or(ErrorKind::UnexpectedEof)?;
+
+        *field = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+    }
+
+    Ok(Some(zfs_arc))
+}
+
+/// Get the ZFS ARC (Adaptive Replacement Cache) information by reading the `/proc/spl/kstat/zfs/arcstats` file. If ZFS is not loaded, it returns `None`.
+///
+/// ```rust
+/// use mprober_lib::memory;
+///
+/// if let Some(zfs_arc) = memory::get_zfs_arc().unwrap() {
+///     println!("{zfs_arc:#?}");
+/// }
+/// ```
+#[inline]
+pub fn get_zfs_arc() -> Result<Option<ZFSARC>, ScannerError> {
+    get_zfs_arc_from_path("/proc/spl/kstat/zfs/arcstats")
+}
+
+/// Get memory information like the `free` command by reading a file in the format of `/proc/meminfo`, and count the reclaimable part of the ZFS ARC from a file in the format of `/proc/spl/kstat/zfs/arcstats` as cache.
+pub fn free_with_zfs_arc_from_paths<P: AsRef<Path>, AP: AsRef<Path>>(
+    meminfo_path: P,
+    arcstats_path: AP,
+) -> Result<Free, ScannerError> {
+    let mut free = free_from_path(meminfo_path)?;
+
+    if let Some(zfs_arc) = get_zfs_arc_from_path(arcstats_path)? {
+        let reclaimable = zfs_arc.get_reclaimable_size() as usize;
+
+        let mem = &mut free.mem;
+
+        // the kernel counts the ARC as used memory, and `MemAvailable` does not include it
+        let reclaimable = reclaimable.min(mem.used);
+
+        mem.used -= reclaimable;
+        mem.cache += reclaimable;
+        mem.available = (mem.available + reclaimable).min(mem.total);
+    }
+
+    Ok(free)
+}
+
+/// Get memory information like the `free` command by reading the `/proc/meminfo` file. If ZFS is loaded, the part of the ZFS ARC which can shrink (the size above `c_min`) is counted as cache instead of used memory by reading the `/proc/spl/kstat/zfs/arcstats` file.
+///
+/// ```rust
+/// use mprober_lib::memory;
+///
+/// let free = memory::free_with_zfs_arc().unwrap();
+///
+/// println!("{free:#?}");
+/// ```
+#[inline]
+pub fn free_with_zfs_arc() -> Result<Free, ScannerError> {
+    free_with_zfs_arc_from_paths("/proc/meminfo", "/proc/spl/kstat/zfs/arcstats")
+}
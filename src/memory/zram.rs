@@ -0,0 +1,105 @@
+use std::{io::ErrorKind, path::Path};
+
+use crate::{
+    functions::{read_one_value, read_selected_value},
+    scanner_rust::{generic_array::typenum::U256, ScannerAscii, ScannerError},
+};
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct Zram {
+    /// Such as `zram0`.
+    pub device:          String,
+    /// The maximum size of uncompressed data, in bytes. (`disksize`)
+    pub disk_size:       u64,
+    /// The compression algorithm in use, such as `lzo-rle` or `zstd`.
+    pub algorithm:       Option<String>,
+    /// The size of uncompressed data stored in this device, in bytes. (`orig_data_size`)
+    pub orig_data_size:  u64,
+    /// The size of compressed data stored in this device, in bytes. (`compr_data_size`)
+    pub compr_data_size: u64,
+    /// The memory used by this device, including the allocator overhead, in bytes. (`mem_used_total`)
+    pub mem_used_total:  u64,
+    /// The maximum memory this device can use, in bytes. `0` means no limit. (`mem_limit`)
+    pub mem_limit:       u64,
+    /// The maximum memory this device has used, in bytes. (`mem_used_max`)
+    pub mem_used_max:    u64,
+    /// The number of pages which are filled with the same value and therefore take no memory. (`same_pages`)
+    pub same_pages:      u64,
+    /// The number of pages freed by compaction. (`pages_compacted`)
+    pub pages_compacted: u64,
+    /// The number of incompressible pages. Since Linux 4.19. (`huge_pages`)
+    pub huge_pages:      Option<u64>,
+}
+
+impl Zram {
+    /// `orig_data_size / compr_data_size`. `None` if this device stores nothing.
+    #[inline]
+    pub fn get_compression_ratio(&self) -> Option<f64> {
+        if self.compr_data_size == 0 {
+            None
+        } else {
+            Some(self.orig_data_size as f64 / self.compr_data_size as f64)
+        }
+    }
+}
+
+/// Get zram device information by reading a folder in the format of `/sys/block/zramN`. If the folder does not exist, it returns `None`.
+pub fn get_zram_from_path<P: AsRef<Path>>(zram_path: P) -> Result<Option<Zram>, ScannerError> {
+    let zram_path = zram_path.as_ref();
+
+    let mut sc: ScannerAscii<_, U256> = match ScannerAscii::scan_path2(zram_path.join("mm_stat")) {
+        Ok(sc) => sc,
+        Err(ScannerError::IOError(err)) if err.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+
+    let orig_data_size = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+    let compr_data_size = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+    let mem_used_total = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+    let mem_limit = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+    let mem_used_max = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+    let same_pages = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+    let pages_compacted = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+    let huge_pages = sc.next_u64()?;
+
+    let device = zram_path
+        .file_name()
+        .map(|device| device.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let disk_size = match read_one_value(zram_path.join("disksize"))? {
+        Some(disk_size) => disk_size.parse()?,
+        None => 0,
+    };
+
+    // `lzo [lzo-rle] lz4 zstd`
+    let algorithm = read_selected_value(zram_path.join("comp_algorithm"))?;
+
+    Ok(Some(Zram {
+        device,
+        disk_size,
+        algorithm,
+        orig_data_size,
+        compr_data_size,
+        mem_used_total,
+        mem_limit,
+        mem_used_max,
+        same_pages,
+        pages_compacted,
+        huge_pages,
+    }))
+}
+
+/// Get zram device information by reading the `/sys/block/<device>` folder. If the device does not exist, it returns `None`.
+///
+/// ```rust
+/// use mprober_lib::memory;
+///
+/// if let Some(zram) = memory::get_zram("zram0").unwrap() {
+///     println!("{:?}", zram.get_compression_ratio());
+/// }
+/// ```
+#[inline]
+pub fn get_zram<S: AsRef<str>>(device: S) -> Result<Option<Zram>, ScannerError> {
+    get_zram_from_path(Path::new("/sys/block").join(device.as_ref()))
+}
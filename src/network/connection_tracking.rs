@@ -0,0 +1,74 @@
+use std::{io::ErrorKind, path::Path};
+
+use crate::scanner_rust::{generic_array::typenum::U24, ScannerAscii, ScannerError};
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct ConnectionTracking {
+    pub count: u64,
+    pub max:   u64,
+}
+
+impl ConnectionTracking {
+    /// Compute the utilization of the connection tracking table. If it returns `1.0`, means `100%` and new connections will be dropped.
+    ///
+    /// ```rust
+    /// use mprober_lib::network;
+    ///
+    /// if let Some(connection_tracking) =
+    ///     network::get_connection_tracking().unwrap()
+    /// {
+    ///     println!("{:.2}%", connection_tracking.utilization() * 100.0);
+    /// }
+    /// ```
+    #[inline]
+    pub fn utilization(&self) -> f64 {
+        if self.max == 0 {
+            0.0
+        } else {
+            self.count as f64 / self.max as f64
+        }
+    }
+}
+
+#[inline]
+fn read_u64<P: AsRef<Path>>(path: P) -> Result<u64, ScannerError> {
+    let mut sc: ScannerAscii<_, U24> = ScannerAscii::scan_path2(path)?;
+
+    Ok(sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?)
+}
+
+/// Get the usage of the connection tracking table by reading files in the format of `/proc/sys/net/netfilter/nf_conntrack_count` and `/proc/sys/net/netfilter/nf_conntrack_max`. If the count file does not exist, it returns `None`.
+pub fn get_connection_tracking_from_paths<P: AsRef<Path>, MP: AsRef<Path>>(
+    count_path: P,
+    max_path: MP,
+) -> Result<Option<ConnectionTracking>, ScannerError> {
+    let count = match read_u64(count_path) {
+        Ok(count) => count,
+        Err(ScannerError::IOError(err)) if err.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+
+    let max = read_u64(max_path)?;
+
+    Ok(Some(ConnectionTracking {
+        count,
+        max,
+    }))
+}
+
+/// Get the usage of the connection tracking table by reading the `/proc/sys/net/netfilter/nf_conntrack_count` file and the `/proc/sys/net/netfilter/nf_conntrack_max` file. If the `nf_conntrack` module is not loaded, it returns `None`.
+///
+/// ```rust
+/// use mprober_lib::network;
+///
+/// let connection_tracking = network::get_connection_tracking().unwrap();
+///
+/// println!("{connection_tracking:#?}");
+/// ```
+#[inline]
+pub fn get_connection_tracking() -> Result<Option<ConnectionTracking>, ScannerError> {
+    get_connection_tracking_from_paths(
+        "/proc/sys/net/netfilter/nf_conntrack_count",
+        "/proc/sys/net/netfilter/nf_conntrack_max",
+    )
+}
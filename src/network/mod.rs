@@ -1,14 +1,29 @@
+mod connection_tracking;
+mod network_address;
+mod network_filter;
+mod network_namespace;
 mod network_stat;
+mod network_wireless;
+mod socket_stat;
 
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
+    fs,
     hash::{Hash, Hasher},
     io::ErrorKind,
+    path::Path,
+    str::from_utf8_unchecked,
     thread::sleep,
     time::Duration,
 };
 
+pub use connection_tracking::*;
+pub use network_address::*;
+pub use network_filter::*;
+pub use network_namespace::*;
 pub use network_stat::*;
+pub use network_wireless::*;
+pub use socket_stat::*;
 
 use crate::scanner_rust::{generic_array::typenum::U1024, ScannerAscii, ScannerError};
 
@@ -16,6 +31,9 @@ use crate::scanner_rust::{generic_array::typenum::U1024, ScannerAscii, ScannerEr
 pub struct Network {
     pub interface: String,
     pub stat:      NetworkStat,
+    pub addresses: Vec<NetworkAddress>,
+    /// `None` if the interface has no wireless extensions.
+    pub wireless:  Option<NetworkWireless>,
 }
 
 impl Hash for Network {
@@ -32,17 +50,65 @@ impl PartialEq for Network {
     }
 }
 
-/// Get network information by reading the `/proc/net/dev` file.
-///
-/// ```rust
-/// use mprober_lib::network;
-///
-/// let networks = network::get_networks().unwrap();
-///
-/// println!("{networks:#?}");
-/// ```
-pub fn get_networks() -> Result<Vec<Network>, ScannerError> {
-    let mut sc: ScannerAscii<_, U1024> = ScannerAscii::scan_path2("/proc/net/dev")?;
+/// `sys_class_net_path` belongs to the network namespace of the current process. If `same_namespace` is `false`, interfaces which are missing in it are not checked by the `physical_only` and `up_only` options.
+fn is_interface_filtered_out(
+    interface: &str,
+    sys_class_net_path: &Path,
+    same_namespace: bool,
+    network_filter: &NetworkFilter,
+) -> Result<bool, ScannerError> {
+    if let Some(interface_filter) = network_filter.interface_filter {
+        if !interface_filter.is_match(interface) {
+            return Ok(true);
+        }
+    }
+
+    if let Some(interface_exclusion_filter) = network_filter.interface_exclusion_filter {
+        if interface_exclusion_filter.is_match(interface) {
+            return Ok(true);
+        }
+    }
+
+    if network_filter.physical_only || network_filter.up_only {
+        let interface_path = sys_class_net_path.join(interface);
+
+        if !same_namespace && !interface_path.exists() {
+            return Ok(false);
+        }
+
+        if network_filter.physical_only && !interface_path.join("device").exists() {
+            return Ok(true);
+        }
+
+        if network_filter.up_only {
+            let flags = match fs::read_to_string(interface_path.join("flags")) {
+                Ok(flags) => flags,
+                Err(err) if err.kind() == ErrorKind::NotFound => return Ok(true),
+                Err(err) => return Err(err.into()),
+            };
+
+            let flags = u32::from_str_radix(flags.trim().trim_start_matches("0x"), 16)?;
+
+            if flags & libc::IFF_UP as u32 == 0 {
+                return Ok(true);
+            }
+        }
+    }
+
+    Ok(false)
+}
+
+fn get_networks_inner<P: AsRef<Path>, SP: AsRef<Path>>(
+    dev_path: P,
+    sys_class_net_path: SP,
+    same_namespace: bool,
+    network_filter: &NetworkFilter,
+    mut network_addresses: HashMap<String, Vec<NetworkAddress>>,
+    mut network_wireless: HashMap<String, NetworkWireless>,
+) -> Result<Vec<Network>, ScannerError> {
+    let sys_class_net_path = sys_class_net_path.as_ref();
+
+    let mut sc: ScannerAscii<_, U1024> = ScannerAscii::scan_path2(dev_path)?;
 
     for _ in 0..2 {
         sc.drop_next_line()?.ok_or(ErrorKind::UnexpectedEof)?;
@@ -51,7 +117,18 @@ pub fn get_networks() -> Result<Vec<Network>, ScannerError> {
     let mut networks = Vec::with_capacity(1);
 
     while let Some(interface) = sc.next_until_raw(":")? {
-        let interface = unsafe { String::from_utf8_unchecked(interface) };
+        let interface = unsafe { from_utf8_unchecked(&interface) }.trim().to_string();
+
+        if is_interface_filtered_out(
+            &interface,
+            sys_class_net_path,
+            same_namespace,
+            network_filter,
+        )? {
+            sc.drop_next_line()?.ok_or(ErrorKind::UnexpectedEof)?;
+
+            continue;
+        }
 
         let receive_bytes = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
 
@@ -66,9 +143,14 @@ pub fn get_networks() -> Result<Vec<Network>, ScannerError> {
             transmit_bytes,
         };
 
+        let addresses = network_addresses.remove(&interface).unwrap_or_default();
+        let wireless = network_wireless.remove(&interface);
+
         let network = Network {
             interface,
             stat,
+            addresses,
+            wireless,
         };
 
         networks.push(network);
@@ -79,23 +161,8 @@ pub fn get_networks() -> Result<Vec<Network>, ScannerError> {
     Ok(networks)
 }
 
-/// Get network information by reading the `/proc/net/dev` file and measure the speed within a specific time interval.
-///
-/// ```rust
-/// use std::time::Duration;
-///
-/// use mprober_lib::network;
-///
-/// let networks_with_speed =
-///     network::get_networks_with_speed(Duration::from_millis(100)).unwrap();
-///
-/// for (network, network_speed) in networks_with_speed {
-///     println!("{}: ", network.interface);
-///     println!("    Receive: {:.1} B/s", network_speed.receive);
-///     println!("    Transmit: {:.1} B/s", network_speed.transmit);
-/// }
-/// ```
-pub fn get_networks_with_speed(
+fn get_networks_with_speed_inner<F: Fn() -> Result<Vec<Network>, ScannerError>>(
+    get_networks: F,
     interval: Duration,
 ) -> Result<Vec<(Network, NetworkSpeed)>, ScannerError> {
     let pre_networks = get_networks()?;
@@ -124,3 +191,231 @@ pub fn get_networks_with_speed(
 
     Ok(networks_with_speed)
 }
+
+/// Get network information by reading a file in the format of `/proc/net/dev` and a file in the format of `/proc/net/wireless`. The `physical_only` and `up_only` options of the filter are checked against a folder in the format of `/sys/class/net`. Addresses are not included.
+pub fn get_networks_from_paths<P: AsRef<Path>, WP: AsRef<Path>, SP: AsRef<Path>>(
+    dev_path: P,
+    wireless_path: WP,
+    sys_class_net_path: SP,
+    network_filter: &NetworkFilter,
+) -> Result<Vec<Network>, ScannerError> {
+    get_networks_inner(
+        dev_path,
+        sys_class_net_path,
+        true,
+        network_filter,
+        HashMap::new(),
+        get_network_wireless_from_path(wireless_path)?,
+    )
+}
+
+/// Get network information of the interfaces which pass the filter by reading the `/proc/net/dev` file and the `/proc/net/wireless` file, and using the `getifaddrs` function in libc. The `physical_only` and `up_only` options of the filter are checked against the `/sys/class/net` folder.
+///
+/// ```rust
+/// use mprober_lib::network;
+///
+/// let networks = network::get_filtered_networks(&network::NetworkFilter {
+///     physical_only: true,
+///     ..network::NetworkFilter::default()
+/// })
+/// .unwrap();
+///
+/// println!("{networks:#?}");
+/// ```
+#[inline]
+pub fn get_filtered_networks(network_filter: &NetworkFilter) -> Result<Vec<Network>, ScannerError> {
+    get_networks_inner(
+        "/proc/net/dev",
+        "/sys/class/net",
+        true,
+        network_filter,
+        get_network_addresses()?,
+        get_network_wireless()?,
+    )
+}
+
+/// Get network information by reading the `/proc/net/dev` file and the `/proc/net/wireless` file, and using the `getifaddrs` function in libc.
+///
+/// ```rust
+/// use mprober_lib::network;
+///
+/// let networks = network::get_networks().unwrap();
+///
+/// println!("{networks:#?}");
+/// ```
+#[inline]
+pub fn get_networks() -> Result<Vec<Network>, ScannerError> {
+    get_filtered_networks(&NetworkFilter::default())
+}
+
+/// Get network information of the interfaces which pass the filter in the network namespace of a specific process found by ID by reading the `/proc/PID/net/dev` file and the `/proc/PID/net/wireless` file. Addresses are only available (by using the `getifaddrs` function in libc) if the process is known to be in the same network namespace as the current process. A `NotFound` error is returned for a process which does not exist, while other errors of `get_network_namespace_id` (such as `PermissionDenied`) only make addresses unavailable.
+///
+/// The `physical_only` and `up_only` options of the filter are checked against `/sys/class/net` of the current process. For a process in another network namespace, an interface which has the same name as an interface of the current process is judged by the interface of the current process, and an interface which does not exist in the current network namespace is not filtered out by these options.
+///
+/// ```rust
+/// use mprober_lib::network;
+///
+/// let networks = network::get_filtered_networks_by_pid(
+///     std::process::id(),
+///     &network::NetworkFilter {
+///         up_only: true,
+///         ..network::NetworkFilter::default()
+///     },
+/// )
+/// .unwrap();
+///
+/// println!("{networks:#?}");
+/// ```
+pub fn get_filtered_networks_by_pid(
+    pid: u32,
+    network_filter: &NetworkFilter,
+) -> Result<Vec<Network>, ScannerError> {
+    let net_path = Path::new("/proc").join(pid.to_string()).join("net");
+
+    let same_namespace = match get_network_namespace_id(pid)
+        .and_then(|id| Ok(id == get_network_namespace_id(std::process::id())?))
+    {
+        Ok(same_namespace) => same_namespace,
+        Err(ScannerError::IOError(err)) if err.kind() == ErrorKind::NotFound => {
+            return Err(err.into())
+        },
+        Err(_) => false,
+    };
+
+    let network_addresses = if same_namespace { get_network_addresses()? } else { HashMap::new() };
+
+    let network_wireless = get_network_wireless_from_path(net_path.join("wireless"))?;
+
+    get_networks_inner(
+        net_path.join("dev"),
+        "/sys/class/net",
+        same_namespace,
+        network_filter,
+        network_addresses,
+        network_wireless,
+    )
+}
+
+/// Get network information in the network namespace of a specific process found by ID by reading the `/proc/PID/net/dev` file and the `/proc/PID/net/wireless` file. Addresses are only available (by using the `getifaddrs` function in libc) if the process is known to be in the same network namespace as the current process. A `NotFound` error is returned for a process which does not exist.
+///
+/// ```rust
+/// use mprober_lib::network;
+///
+/// let networks = network::get_networks_by_pid(std::process::id()).unwrap();
+///
+/// println!("{networks:#?}");
+/// ```
+#[inline]
+pub fn get_networks_by_pid(pid: u32) -> Result<Vec<Network>, ScannerError> {
+    get_filtered_networks_by_pid(pid, &NetworkFilter::default())
+}
+
+/// Get network information of the interfaces which pass the filter by reading the `/proc/net/dev` file and the `/proc/net/wireless` file, and using the `getifaddrs` function in libc. And measure the speed within a specific time interval.
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use mprober_lib::network;
+///
+/// let networks_with_speed = network::get_filtered_networks_with_speed(
+///     &network::NetworkFilter {
+///         physical_only: true,
+///         up_only: true,
+///         ..network::NetworkFilter::default()
+///     },
+///     Duration::from_millis(100),
+/// )
+/// .unwrap();
+///
+/// for (network, network_speed) in networks_with_speed {
+///     println!("{}: ", network.interface);
+///     println!("    Receive: {:.1} B/s", network_speed.receive);
+///     println!("    Transmit: {:.1} B/s", network_speed.transmit);
+/// }
+/// ```
+#[inline]
+pub fn get_filtered_networks_with_speed(
+    network_filter: &NetworkFilter,
+    interval: Duration,
+) -> Result<Vec<(Network, NetworkSpeed)>, ScannerError> {
+    get_networks_with_speed_inner(|| get_filtered_networks(network_filter), interval)
+}
+
+/// Get network information by reading the `/proc/net/dev` file and the `/proc/net/wireless` file, and using the `getifaddrs` function in libc. And measure the speed within a specific time interval.
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use mprober_lib::network;
+///
+/// let networks_with_speed =
+///     network::get_networks_with_speed(Duration::from_millis(100)).unwrap();
+///
+/// for (network, network_speed) in networks_with_speed {
+///     println!("{}: ", network.interface);
+///     println!("    Receive: {:.1} B/s", network_speed.receive);
+///     println!("    Transmit: {:.1} B/s", network_speed.transmit);
+/// }
+/// ```
+#[inline]
+pub fn get_networks_with_speed(
+    interval: Duration,
+) -> Result<Vec<(Network, NetworkSpeed)>, ScannerError> {
+    get_filtered_networks_with_speed(&NetworkFilter::default(), interval)
+}
+
+/// Get network information of the interfaces which pass the filter in the network namespace of a specific process found by ID by reading the `/proc/PID/net/dev` file and the `/proc/PID/net/wireless` file. And measure the speed within a specific time interval.
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use mprober_lib::network;
+///
+/// let networks_with_speed = network::get_filtered_networks_with_speed_by_pid(
+///     std::process::id(),
+///     &network::NetworkFilter::default(),
+///     Duration::from_millis(100),
+/// )
+/// .unwrap();
+///
+/// for (network, network_speed) in networks_with_speed {
+///     println!("{}: ", network.interface);
+///     println!("    Receive: {:.1} B/s", network_speed.receive);
+///     println!("    Transmit: {:.1} B/s", network_speed.transmit);
+/// }
+/// ```
+#[inline]
+pub fn get_filtered_networks_with_speed_by_pid(
+    pid: u32,
+    network_filter: &NetworkFilter,
+    interval: Duration,
+) -> Result<Vec<(Network, NetworkSpeed)>, ScannerError> {
+    get_networks_with_speed_inner(|| get_filtered_networks_by_pid(pid, network_filter), interval)
+}
+
+/// Get network information in the network namespace of a specific process found by ID by reading the `/proc/PID/net/dev` file and the `/proc/PID/net/wireless` file. And measure the speed within a specific time interval.
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use mprober_lib::network;
+///
+/// let networks_with_speed = network::get_networks_with_speed_by_pid(
+///     std::process::id(),
+///     Duration::from_millis(100),
+/// )
+/// .unwrap();
+///
+/// for (network, network_speed) in networks_with_speed {
+///     println!("{}: ", network.interface);
+///     println!("    Receive: {:.1} B/s", network_speed.receive);
+///     println!("    Transmit: {:.1} B/s", network_speed.transmit);
+/// }
+/// ```
+#[inline]
+pub fn get_networks_with_speed_by_pid(
+    pid: u32,
+    interval: Duration,
+) -> Result<Vec<(Network, NetworkSpeed)>, ScannerError> {
+    get_filtered_networks_with_speed_by_pid(pid, &NetworkFilter::default(), interval)
+}
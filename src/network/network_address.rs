@@ -0,0 +1,217 @@
+use std::{
+    collections::HashMap,
+    ffi::CStr,
+    io,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    ptr::null_mut,
+};
+
+use crate::scanner_rust::ScannerError;
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum NetworkAddressScope {
+    #[default]
+    Global,
+    Site,
+    Link,
+    Host,
+}
+
+impl NetworkAddressScope {
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            NetworkAddressScope::Global => "global",
+            NetworkAddressScope::Site => "site",
+            NetworkAddressScope::Link => "link",
+            NetworkAddressScope::Host => "host",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct NetworkAddress {
+    pub address:       IpAddr,
+    pub prefix_length: u8,
+    /// Only available for IPv4 addresses on interfaces which support broadcasting.
+    pub broadcast:     Option<IpAddr>,
+    pub scope:         NetworkAddressScope,
+}
+
+impl NetworkAddress {
+    /// Create a `NetworkAddress` from an address, its netmask and its broadcast address. The prefix length is the number of set bits of the netmask, and the broadcast address is dropped for IPv6 addresses.
+    ///
+    /// The scope is derived from the address, so it can differ from the scope shown by `ip addr` if the address was configured with an explicit scope. IPv4 loopback addresses are in the host scope and all the other IPv4 addresses, including link-local ones in `169.254.0.0/16`, are in the global scope, which is what the kernel assigns by default. IPv6 scopes follow the address type, as the kernel does.
+    ///
+    /// ```rust
+    /// use std::net::{IpAddr, Ipv4Addr};
+    ///
+    /// use mprober_lib::network::{NetworkAddress, NetworkAddressScope};
+    ///
+    /// let network_address = NetworkAddress::new(
+    ///     IpAddr::V4(Ipv4Addr::new(192, 168, 1, 10)),
+    ///     Some(IpAddr::V4(Ipv4Addr::new(255, 255, 255, 0))),
+    ///     Some(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 255))),
+    /// );
+    ///
+    /// assert_eq!(24, network_address.prefix_length);
+    /// assert_eq!(NetworkAddressScope::Global, network_address.scope);
+    /// ```
+    pub fn new(address: IpAddr, netmask: Option<IpAddr>, broadcast: Option<IpAddr>) -> Self {
+        let prefix_length = match netmask {
+            Some(netmask) => netmask_to_prefix_length(netmask),
+            None => 0,
+        };
+
+        let (broadcast, scope) = match address {
+            IpAddr::V4(address) => (broadcast, ipv4_scope(address)),
+            IpAddr::V6(address) => (None, ipv6_scope(address)),
+        };
+
+        NetworkAddress {
+            address,
+            prefix_length,
+            broadcast,
+            scope,
+        }
+    }
+
+    #[inline]
+    pub fn is_ipv4(&self) -> bool {
+        self.address.is_ipv4()
+    }
+
+    #[inline]
+    pub fn is_ipv6(&self) -> bool {
+        self.address.is_ipv6()
+    }
+}
+
+/// `getifaddrs` does not provide scopes. The kernel only puts IPv4 loopback addresses in the host scope by itself, so `169.254.0.0/16` is not treated as link-local here.
+#[inline]
+fn ipv4_scope(address: Ipv4Addr) -> NetworkAddressScope {
+    if address.is_loopback() {
+        NetworkAddressScope::Host
+    } else {
+        NetworkAddressScope::Global
+    }
+}
+
+#[inline]
+fn ipv6_scope(address: Ipv6Addr) -> NetworkAddressScope {
+    let segments = address.segments();
+
+    if address.is_loopback() {
+        NetworkAddressScope::Host
+    } else if segments[0] & 0xFFC0 == 0xFE80 {
+        NetworkAddressScope::Link
+    } else if segments[0] & 0xFFC0 == 0xFEC0 {
+        NetworkAddressScope::Site
+    } else {
+        NetworkAddressScope::Global
+    }
+}
+
+unsafe fn sockaddr_to_ip_addr(sockaddr: *const libc::sockaddr) -> Option<IpAddr> {
+    if sockaddr.is_null() {
+        return None;
+    }
+
+    match (*sockaddr).sa_family as libc::c_int {
+        libc::AF_INET => {
+            let sockaddr = &*(sockaddr as *const libc::sockaddr_in);
+
+            Some(IpAddr::V4(Ipv4Addr::from(u32::from_be(sockaddr.sin_addr.s_addr))))
+        },
+        libc::AF_INET6 => {
+            let sockaddr = &*(sockaddr as *const libc::sockaddr_in6);
+
+            Some(IpAddr::V6(Ipv6Addr::from(sockaddr.sin6_addr.s6_addr)))
+        },
+        _ => None,
+    }
+}
+
+#[inline]
+fn netmask_to_prefix_length(netmask: IpAddr) -> u8 {
+    match netmask {
+        IpAddr::V4(netmask) => u32::from(netmask).count_ones() as u8,
+        IpAddr::V6(netmask) => u128::from(netmask).count_ones() as u8,
+    }
+}
+
+/// Get the interface name of an address label. IPv4 addresses can be labelled in the form of `interface:alias` (such as `eth0:1`), and only the interface part appears in `/proc/net/dev`.
+///
+/// ```rust
+/// use mprober_lib::network;
+///
+/// assert_eq!("eth0", network::get_interface_of_label("eth0:1"));
+/// assert_eq!("eth0", network::get_interface_of_label("eth0"));
+/// ```
+#[inline]
+pub fn get_interface_of_label(label: &str) -> &str {
+    match label.split_once(':') {
+        Some((interface, _)) => interface,
+        None => label,
+    }
+}
+
+/// Get IPv4 and IPv6 addresses of all network interfaces using the `getifaddrs` function in libc. The keys of the returned map are interface names, so the addresses of aliases such as `eth0:1` are put under `eth0`. See `NetworkAddress::new` for how the scopes are determined.
+///
+/// ```rust
+/// use mprober_lib::network;
+///
+/// let network_addresses = network::get_network_addresses().unwrap();
+///
+/// println!("{network_addresses:#?}");
+/// ```
+pub fn get_network_addresses() -> Result<HashMap<String, Vec<NetworkAddress>>, ScannerError> {
+    let mut ifaddrs: *mut libc::ifaddrs = null_mut();
+
+    if unsafe { libc::getifaddrs(&mut ifaddrs as *mut _) } != 0 {
+        return Err(io::Error::last_os_error().into());
+    }
+
+    let mut network_addresses: HashMap<String, Vec<NetworkAddress>> = HashMap::with_capacity(1);
+
+    let mut p = ifaddrs;
+
+    while !p.is_null() {
+        let ifaddr = unsafe { &*p };
+
+        p = ifaddr.ifa_next;
+
+        let address = match unsafe { sockaddr_to_ip_addr(ifaddr.ifa_addr) } {
+            Some(address) => address,
+            None => continue,
+        };
+
+        let netmask = unsafe { sockaddr_to_ip_addr(ifaddr.ifa_netmask) };
+
+        let broadcast = if ifaddr.ifa_flags & libc::IFF_BROADCAST as libc::c_uint != 0 {
+            unsafe { sockaddr_to_ip_addr(ifaddr.ifa_ifu) }
+        } else {
+            None
+        };
+
+        let label = unsafe { CStr::from_ptr(ifaddr.ifa_name) }.to_string_lossy();
+        let interface = get_interface_of_label(&label).to_string();
+
+        let network_address = NetworkAddress::new(address, netmask, broadcast);
+
+        match network_addresses.get_mut(&interface) {
+            Some(addresses) => {
+                addresses.push(network_address);
+            },
+            None => {
+                network_addresses.insert(interface, vec![network_address]);
+            },
+        }
+    }
+
+    unsafe {
+        libc::freeifaddrs(ifaddrs);
+    }
+
+    Ok(network_addresses)
+}
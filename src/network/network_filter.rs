@@ -0,0 +1,13 @@
+use regex::Regex;
+
+#[derive(Default, Debug, Clone)]
+pub struct NetworkFilter<'a> {
+    /// Only include interfaces whose names match this regular expression.
+    pub interface_filter:           Option<&'a Regex>,
+    /// Exclude interfaces whose names match this regular expression.
+    pub interface_exclusion_filter: Option<&'a Regex>,
+    /// Only include interfaces backed by a device (the `/sys/class/net/INTERFACE/device` path exists).
+    pub physical_only:              bool,
+    /// Only include interfaces whose `IFF_UP` flag in `/sys/class/net/INTERFACE/flags` is set.
+    pub up_only:                    bool,
+}
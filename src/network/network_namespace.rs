@@ -0,0 +1,113 @@
+use std::{collections::BTreeMap, fs, io::ErrorKind, os::unix::fs::MetadataExt, path::Path};
+
+use crate::scanner_rust::ScannerError;
+
+/// Identifies a network namespace. Inode numbers are only unique within a device (the `nsfs` filesystem), so both of them are compared.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct NetworkNamespaceID {
+    /// The device number of the `/proc/PID/ns/net` file. (`st_dev`)
+    pub device: u64,
+    /// The inode number of the `/proc/PID/ns/net` file. (`st_ino`)
+    pub inode:  u64,
+}
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct NetworkNamespace {
+    pub id:   NetworkNamespaceID,
+    /// IDs of processes in this namespace. They are sorted in ascending order.
+    pub pids: Vec<u32>,
+}
+
+/// Get the ID of a network namespace by reading a file in the format of `/proc/PID/ns/net`.
+#[inline]
+pub fn get_network_namespace_id_from_path<P: AsRef<Path>>(
+    ns_path: P,
+) -> Result<NetworkNamespaceID, ScannerError> {
+    let metadata = fs::metadata(ns_path)?;
+
+    Ok(NetworkNamespaceID {
+        device: metadata.dev(), inode: metadata.ino()
+    })
+}
+
+/// Get the ID of the network namespace of a specific process found by ID by reading the `/proc/PID/ns/net` file. If the process does not exist, a `NotFound` error is returned. If the process belongs to another user and the current process lacks `CAP_SYS_PTRACE`, a `PermissionDenied` error is returned.
+///
+/// ```rust
+/// use mprober_lib::network;
+///
+/// let network_namespace_id =
+///     network::get_network_namespace_id(std::process::id()).unwrap();
+///
+/// println!("{network_namespace_id:?}");
+/// ```
+#[inline]
+pub fn get_network_namespace_id(pid: u32) -> Result<NetworkNamespaceID, ScannerError> {
+    get_network_namespace_id_from_path(
+        Path::new("/proc").join(pid.to_string()).join("ns").join("net"),
+    )
+}
+
+/// Get distinct network namespaces by reading the `ns/net` files in the `PID` folders of a folder in the format of `/proc`. Processes which are inaccessible are ignored.
+pub fn get_network_namespaces_from_path<P: AsRef<Path>>(
+    proc_path: P,
+) -> Result<Vec<NetworkNamespace>, ScannerError> {
+    let mut id_pids_map: BTreeMap<NetworkNamespaceID, Vec<u32>> = BTreeMap::new();
+
+    for dir_entry in proc_path.as_ref().read_dir()? {
+        let dir_entry = dir_entry?;
+
+        if let Some(file_name) = dir_entry.file_name().to_str() {
+            if let Ok(pid) = file_name.parse::<u32>() {
+                match get_network_namespace_id_from_path(dir_entry.path().join("ns").join("net")) {
+                    Ok(id) => id_pids_map.entry(id).or_default().push(pid),
+                    Err(err) => {
+                        if let ScannerError::IOError(err) = &err {
+                            if matches!(
+                                err.kind(),
+                                ErrorKind::NotFound | ErrorKind::PermissionDenied
+                            ) {
+                                continue;
+                            }
+                        }
+
+                        return Err(err);
+                    },
+                }
+            }
+        }
+    }
+
+    let network_namespaces = id_pids_map
+        .into_iter()
+        .map(|(id, mut pids)| {
+            pids.sort_unstable();
+
+            NetworkNamespace {
+                id,
+                pids,
+            }
+        })
+        .collect();
+
+    Ok(network_namespaces)
+}
+
+/// Get distinct network namespaces by reading the `/proc/PID/ns/net` files. Processes which are inaccessible are ignored.
+///
+/// ```rust
+/// use mprober_lib::network;
+///
+/// let network_namespaces = network::get_network_namespaces().unwrap();
+///
+/// for network_namespace in network_namespaces {
+///     if let Ok(networks) =
+///         network::get_networks_by_pid(network_namespace.pids[0])
+///     {
+///         println!("{:?}: {networks:#?}", network_namespace.id);
+///     }
+/// }
+/// ```
+#[inline]
+pub fn get_network_namespaces() -> Result<Vec<NetworkNamespace>, ScannerError> {
+    get_network_namespaces_from_path("/proc")
+}
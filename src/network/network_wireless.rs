@@ -0,0 +1,109 @@
+use std::{collections::HashMap, io::ErrorKind, path::Path, str::from_utf8_unchecked};
+
+use crate::scanner_rust::{generic_array::typenum::U1024, ScannerAscii, ScannerError};
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct NetworkWireless {
+    pub status:          u16,
+    pub link_quality:    i32,
+    /// In dBm if the driver reports it that way (a negative value), otherwise a relative value.
+    pub signal_level:    i32,
+    /// In dBm if the driver reports it that way (a negative value), otherwise a relative value.
+    pub noise_level:     i32,
+    pub discarded_nwid:  u64,
+    pub discarded_crypt: u64,
+    pub discarded_frag:  u64,
+    pub discarded_retry: u64,
+    pub discarded_misc:  u64,
+    pub missed_beacon:   u64,
+}
+
+#[inline]
+fn parse_quality(value: Vec<u8>) -> Result<i32, ScannerError> {
+    let value = unsafe { from_utf8_unchecked(&value) };
+
+    // the kernel appends a `.` to values which have been updated since the last read
+    Ok(value.trim_end_matches('.').parse()?)
+}
+
+/// Get wireless statistics of network interfaces by reading a file in the format of `/proc/net/wireless`. The keys of the returned map are interface names.
+///
+/// ```rust
+/// use mprober_lib::network;
+///
+/// let network_wireless =
+///     network::get_network_wireless_from_path("/proc/net/wireless").unwrap();
+///
+/// println!("{network_wireless:#?}");
+/// ```
+pub fn get_network_wireless_from_path<P: AsRef<Path>>(
+    path: P,
+) -> Result<HashMap<String, NetworkWireless>, ScannerError> {
+    let mut sc: ScannerAscii<_, U1024> = match ScannerAscii::scan_path2(path) {
+        Ok(sc) => sc,
+        Err(ScannerError::IOError(err)) if err.kind() == ErrorKind::NotFound => {
+            return Ok(HashMap::new())
+        },
+        Err(err) => return Err(err),
+    };
+
+    for _ in 0..2 {
+        if sc.drop_next_line()?.is_none() {
+            return Ok(HashMap::new());
+        }
+    }
+
+    let mut network_wireless = HashMap::with_capacity(1);
+
+    while let Some(interface) = sc.next_until_raw(":")? {
+        let interface = unsafe { from_utf8_unchecked(&interface) }.trim().to_string();
+
+        let status = {
+            let status = sc.next_raw()?.ok_or(ErrorKind::UnexpectedEof)?;
+
+            u16::from_str_radix(unsafe { from_utf8_unchecked(&status) }, 16)?
+        };
+
+        let link_quality = parse_quality(sc.next_raw()?.ok_or(ErrorKind::UnexpectedEof)?)?;
+        let signal_level = parse_quality(sc.next_raw()?.ok_or(ErrorKind::UnexpectedEof)?)?;
+        let noise_level = parse_quality(sc.next_raw()?.ok_or(ErrorKind::UnexpectedEof)?)?;
+
+        let discarded_nwid = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+        let discarded_crypt = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+        let discarded_frag = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+        let discarded_retry = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+        let discarded_misc = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+        let missed_beacon = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+
+        network_wireless.insert(interface, NetworkWireless {
+            status,
+            link_quality,
+            signal_level,
+            noise_level,
+            discarded_nwid,
+            discarded_crypt,
+            discarded_frag,
+            discarded_retry,
+            discarded_misc,
+            missed_beacon,
+        });
+
+        sc.drop_next_line()?;
+    }
+
+    Ok(network_wireless)
+}
+
+/// Get wireless statistics of network interfaces by reading the `/proc/net/wireless` file. The keys of the returned map are interface names. Interfaces without wireless extensions are not included.
+///
+/// ```rust
+/// use mprober_lib::network;
+///
+/// let network_wireless = network::get_network_wireless().unwrap();
+///
+/// println!("{network_wireless:#?}");
+/// ```
+#[inline]
+pub fn get_network_wireless() -> Result<HashMap<String, NetworkWireless>, ScannerError> {
+    get_network_wireless_from_path("/proc/net/wireless")
+}
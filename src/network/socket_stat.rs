@@ -0,0 +1,276 @@
+use std::{io::ErrorKind, path::Path, str::from_utf8_unchecked};
+
+use page_size::get as get_page_size;
+
+use crate::scanner_rust::{
+    generic_array::typenum::{U128, U64},
+    ScannerAscii, ScannerError,
+};
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct TCPSocketStat {
+    pub inuse:  u64,
+    pub orphan: u64,
+    /// Sockets in the TIME_WAIT state.
+    pub tw:     u64,
+    pub alloc:  u64,
+    /// In bytes.
+    pub mem:    u64,
+}
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct UDPSocketStat {
+    pub inuse: u64,
+    /// In bytes.
+    pub mem:   u64,
+}
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct FragmentSocketStat {
+    pub inuse:  u64,
+    /// In bytes.
+    pub memory: u64,
+}
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct SocketLimits {
+    /// `min`, `pressure` and `max` thresholds of TCP memory in bytes.
+    pub tcp_mem:            [u64; 3],
+    /// `min`, `pressure` and `max` thresholds of UDP memory in bytes.
+    pub udp_mem:            [u64; 3],
+    pub tcp_max_orphans:    u64,
+    pub tcp_max_tw_buckets: u64,
+}
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct SocketStat {
+    pub sockets_used:   u64,
+    pub tcp:            TCPSocketStat,
+    pub udp:            UDPSocketStat,
+    pub udplite_inuse:  u64,
+    pub raw_inuse:      u64,
+    pub frag:           FragmentSocketStat,
+    pub tcp6_inuse:     u64,
+    pub udp6_inuse:     u64,
+    pub udplite6_inuse: u64,
+    pub raw6_inuse:     u64,
+    pub frag6:          FragmentSocketStat,
+    pub limits:         SocketLimits,
+}
+
+#[inline]
+fn ratio(value: u64, limit: u64) -> f64 {
+    if limit == 0 {
+        0.0
+    } else {
+        value as f64 / limit as f64
+    }
+}
+
+impl SocketStat {
+    /// Compute the utilization of TCP memory against the `max` threshold in `tcp_mem`. If it returns `1.0`, means `100%`.
+    ///
+    /// ```rust
+    /// use mprober_lib::network;
+    ///
+    /// let socket_stat = network::get_socket_stat().unwrap();
+    ///
+    /// println!("{:.2}%", socket_stat.tcp_memory_utilization() * 100.0);
+    /// ```
+    #[inline]
+    pub fn tcp_memory_utilization(&self) -> f64 {
+        ratio(self.tcp.mem, self.limits.tcp_mem[2])
+    }
+
+    /// Compute the utilization of UDP memory against the `max` threshold in `udp_mem`. If it returns `1.0`, means `100%`.
+    ///
+    /// ```rust
+    /// use mprober_lib::network;
+    ///
+    /// let socket_stat = network::get_socket_stat().unwrap();
+    ///
+    /// println!("{:.2}%", socket_stat.udp_memory_utilization() * 100.0);
+    /// ```
+    #[inline]
+    pub fn udp_memory_utilization(&self) -> f64 {
+        ratio(self.udp.mem, self.limits.udp_mem[2])
+    }
+
+    /// Compute the utilization of orphaned TCP sockets against `tcp_max_orphans`. If it returns `1.0`, means `100%`.
+    ///
+    /// ```rust
+    /// use mprober_lib::network;
+    ///
+    /// let socket_stat = network::get_socket_stat().unwrap();
+    ///
+    /// println!("{:.2}%", socket_stat.tcp_orphan_utilization() * 100.0);
+    /// ```
+    #[inline]
+    pub fn tcp_orphan_utilization(&self) -> f64 {
+        ratio(self.tcp.orphan, self.limits.tcp_max_orphans)
+    }
+
+    /// Compute the utilization of TCP sockets in the TIME_WAIT state against `tcp_max_tw_buckets`. If it returns `1.0`, means `100%`.
+    ///
+    /// ```rust
+    /// use mprober_lib::network;
+    ///
+    /// let socket_stat = network::get_socket_stat().unwrap();
+    ///
+    /// println!("{:.2}%", socket_stat.tcp_time_wait_utilization() * 100.0);
+    /// ```
+    #[inline]
+    pub fn tcp_time_wait_utilization(&self) -> f64 {
+        ratio(self.tcp.tw, self.limits.tcp_max_tw_buckets)
+    }
+}
+
+fn parse_sockstat<P: AsRef<Path>>(
+    path: P,
+    socket_stat: &mut SocketStat,
+    page_size: u64,
+) -> Result<(), ScannerError> {
+    let mut sc: ScannerAscii<_, U128> = ScannerAscii::scan_path2(path)?;
+
+    while let Some(line) = sc.next_line_raw()? {
+        let line = unsafe { from_utf8_unchecked(&line) };
+
+        let mut tokens = line.split_ascii_whitespace();
+
+        let label = match tokens.next() {
+            Some(label) => label,
+            None => continue,
+        };
+
+        while let Some(key) = tokens.next() {
+            let value: u64 = tokens.next().ok_or(ErrorKind::UnexpectedEof)?.parse()?;
+
+            match (label, key) {
+                ("sockets:", "used") => socket_stat.sockets_used = value,
+                ("TCP:", "inuse") => socket_stat.tcp.inuse = value,
+                ("TCP:", "orphan") => socket_stat.tcp.orphan = value,
+                ("TCP:", "tw") => socket_stat.tcp.tw = value,
+                ("TCP:", "alloc") => socket_stat.tcp.alloc = value,
+                ("TCP:", "mem") => socket_stat.tcp.mem = value * page_size,
+                ("UDP:", "inuse") => socket_stat.udp.inuse = value,
+                ("UDP:", "mem") => socket_stat.udp.mem = value * page_size,
+                ("UDPLITE:", "inuse") => socket_stat.udplite_inuse = value,
+                ("RAW:", "inuse") => socket_stat.raw_inuse = value,
+                ("FRAG:", "inuse") => socket_stat.frag.inuse = value,
+                ("FRAG:", "memory") => socket_stat.frag.memory = value,
+                ("TCP6:", "inuse") => socket_stat.tcp6_inuse = value,
+                ("UDP6:", "inuse") => socket_stat.udp6_inuse = value,
+                ("UDPLITE6:", "inuse") => socket_stat.udplite6_inuse = value,
+                ("RAW6:", "inuse") => socket_stat.raw6_inuse = value,
+                ("FRAG6:", "inuse") => socket_stat.frag6.inuse = value,
+                ("FRAG6:", "memory") => socket_stat.frag6.memory = value,
+                _ => (),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn read_u64s<const N: usize, P: AsRef<Path>>(path: P) -> Result<[u64; N], ScannerError> {
+    let mut sc: ScannerAscii<_, U64> = ScannerAscii::scan_path2(path)?;
+
+    let mut values = [0u64; N];
+
+    for value in values.iter_mut() {
+        *value = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+    }
+
+    Ok(values)
+}
+
+/// Get socket limits by reading files in a folder in the format of `/proc/sys/net/ipv4`.
+pub fn get_socket_limits_from_path<P: AsRef<Path>>(
+    ipv4_path: P,
+) -> Result<SocketLimits, ScannerError> {
+    let ipv4_path = ipv4_path.as_ref();
+    let page_size = get_page_size() as u64;
+
+    let tcp_mem = read_u64s::<3, _>(ipv4_path.join("tcp_mem"))?.map(|v| v * page_size);
+    let udp_mem = read_u64s::<3, _>(ipv4_path.join("udp_mem"))?.map(|v| v * page_size);
+    let [tcp_max_orphans] = read_u64s::<1, _>(ipv4_path.join("tcp_max_orphans"))?;
+    let [tcp_max_tw_buckets] = read_u64s::<1, _>(ipv4_path.join("tcp_max_tw_buckets"))?;
+
+    Ok(SocketLimits {
+        tcp_mem,
+        udp_mem,
+        tcp_max_orphans,
+        tcp_max_tw_buckets,
+    })
+}
+
+/// Get socket limits by reading files in the `/proc/sys/net/ipv4` folder.
+///
+/// ```rust
+/// use mprober_lib::network;
+///
+/// let socket_limits = network::get_socket_limits().unwrap();
+///
+/// println!("{socket_limits:#?}");
+/// ```
+#[inline]
+pub fn get_socket_limits() -> Result<SocketLimits, ScannerError> {
+    get_socket_limits_from_path("/proc/sys/net/ipv4")
+}
+
+/// Get socket statistics by reading the `sockstat` and `sockstat6` files in a folder in the format of `/proc/net`, and socket limits from a folder in the format of `/proc/sys/net/ipv4`.
+pub fn get_socket_stat_from_paths<P: AsRef<Path>, IP: AsRef<Path>>(
+    net_path: P,
+    ipv4_path: IP,
+) -> Result<SocketStat, ScannerError> {
+    let net_path = net_path.as_ref();
+    let page_size = get_page_size() as u64;
+
+    let mut socket_stat = SocketStat::default();
+
+    parse_sockstat(net_path.join("sockstat"), &mut socket_stat, page_size)?;
+
+    match parse_sockstat(net_path.join("sockstat6"), &mut socket_stat, page_size) {
+        Ok(_) => (),
+        // IPv6 is disabled
+        Err(ScannerError::IOError(err)) if err.kind() == ErrorKind::NotFound => (),
+        Err(err) => return Err(err),
+    }
+
+    socket_stat.limits = get_socket_limits_from_path(ipv4_path)?;
+
+    Ok(socket_stat)
+}
+
+/// Get socket statistics by reading the `/proc/net/sockstat` file, the `/proc/net/sockstat6` file and files in the `/proc/sys/net/ipv4` folder.
+///
+/// ```rust
+/// use mprober_lib::network;
+///
+/// let socket_stat = network::get_socket_stat().unwrap();
+///
+/// println!("{socket_stat:#?}");
+/// ```
+#[inline]
+pub fn get_socket_stat() -> Result<SocketStat, ScannerError> {
+    get_socket_stat_from_paths("/proc/net", "/proc/sys/net/ipv4")
+}
+
+/// Get socket statistics in the network namespace of a specific process found by ID by reading the `/proc/PID/net/sockstat` file, the `/proc/PID/net/sockstat6` file and files in the `/proc/sys/net/ipv4` folder.
+///
+/// The limits are always those of the network namespace of the current process, because the kernel serves `/proc/sys/net` from the namespace of the reader, even through `/proc/PID/root/proc/sys`. To get the limits of another namespace, call this function from a process (or thread) which has joined that namespace via `setns`.
+///
+/// ```rust
+/// use mprober_lib::network;
+///
+/// let socket_stat = network::get_socket_stat_by_pid(1).unwrap();
+///
+/// println!("{socket_stat:#?}");
+/// ```
+#[inline]
+pub fn get_socket_stat_by_pid(pid: u32) -> Result<SocketStat, ScannerError> {
+    get_socket_stat_from_paths(
+        Path::new("/proc").join(pid.to_string()).join("net"),
+        "/proc/sys/net/ipv4",
+    )
+}
@@ -0,0 +1,5 @@
+mod pressure_stat;
+mod pressure_trigger;
+
+pub use pressure_stat::*;
+pub use pressure_trigger::*;
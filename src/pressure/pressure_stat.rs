@@ -0,0 +1,166 @@
+use std::{
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    str::from_utf8_unchecked,
+};
+
+use crate::scanner_rust::{generic_array::typenum::U128, ScannerAscii, ScannerError};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum PressureResource {
+    CPU,
+    Memory,
+    IO,
+    /// Needs `CONFIG_IRQ_TIME_ACCOUNTING`. Only the `full` line is provided.
+    IRQ,
+}
+
+impl PressureResource {
+    /// The name used in the `/proc/pressure` folder and in the `<name>.pressure` files of cgroups.
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PressureResource::CPU => "cpu",
+            PressureResource::Memory => "memory",
+            PressureResource::IO => "io",
+            PressureResource::IRQ => "irq",
+        }
+    }
+
+    /// The path of the system-wide pressure file.
+    #[inline]
+    pub fn get_path(self) -> PathBuf {
+        Path::new("/proc/pressure").join(self.as_str())
+    }
+
+    /// The path of the pressure file in a cgroup (v2) folder such as `/sys/fs/cgroup/system.slice`.
+    #[inline]
+    pub fn get_cgroup_path<P: AsRef<Path>>(self, cgroup_path: P) -> PathBuf {
+        cgroup_path.as_ref().join(format!("{}.pressure", self.as_str()))
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct PressureAverage {
+    /// The share of time stalled in the last 10 seconds. If it is `1.0`, means `100%`.
+    pub avg10:  f64,
+    /// The share of time stalled in the last 60 seconds. If it is `1.0`, means `100%`.
+    pub avg60:  f64,
+    /// The share of time stalled in the last 300 seconds. If it is `1.0`, means `100%`.
+    pub avg300: f64,
+    /// The total stall time, in microseconds.
+    pub total:  u64,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct Pressure {
+    /// Some tasks are stalled on the resource.
+    pub some: Option<PressureAverage>,
+    /// All non-idle tasks are stalled on the resource simultaneously.
+    pub full: Option<PressureAverage>,
+}
+
+#[inline]
+fn next_field<'a>(field: &'a [u8], name: &[u8]) -> Result<&'a str, ScannerError> {
+    match field.strip_prefix(name).and_then(|field| field.strip_prefix(b"=")) {
+        Some(value) => Ok(unsafe { from_utf8_unchecked(value) }),
+        None => Err(ErrorKind::InvalidData.into()),
+    }
+}
+
+/// Get the pressure stall information by reading a file in the format of `/proc/pressure/*`.
+pub fn get_pressure_from_path<P: AsRef<Path>>(path: P) -> Result<Pressure, ScannerError> {
+    let mut sc: ScannerAscii<_, U128> = ScannerAscii::scan_path2(path)?;
+
+    let mut pressure = Pressure::default();
+
+    while let Some(label) = sc.next_raw()? {
+        let avg10 = sc.next_raw()?.ok_or(ErrorKind::UnexpectedEof)?;
+        let avg60 = sc.next_raw()?.ok_or(ErrorKind::UnexpectedEof)?;
+        let avg300 = sc.next_raw()?.ok_or(ErrorKind::UnexpectedEof)?;
+        let total = sc.next_raw()?.ok_or(ErrorKind::UnexpectedEof)?;
+
+        let average = PressureAverage {
+            avg10:  next_field(&avg10, b"avg10")?.parse::<f64>()? / 100.0,
+            avg60:  next_field(&avg60, b"avg60")?.parse::<f64>()? / 100.0,
+            avg300: next_field(&avg300, b"avg300")?.parse::<f64>()? / 100.0,
+            total:  next_field(&total, b"total")?.parse()?,
+        };
+
+        match label.as_slice() {
+            b"some" => pressure.some = Some(average),
+            b"full" => pressure.full = Some(average),
+            _ => return Err(ErrorKind::InvalidData.into()),
+        }
+    }
+
+    Ok(pressure)
+}
+
+/// Get the system-wide pressure stall information of a resource by reading the `/proc/pressure/<resource>` file. The kernel needs to be built with `CONFIG_PSI` and not booted with `psi=0`, or a `NotFound` error is returned.
+///
+/// ```rust
+/// use mprober_lib::pressure;
+///
+/// if let Ok(pressure) =
+///     pressure::get_pressure(pressure::PressureResource::Memory)
+/// {
+///     println!("{pressure:#?}");
+/// }
+/// ```
+#[inline]
+pub fn get_pressure(resource: PressureResource) -> Result<Pressure, ScannerError> {
+    get_pressure_from_path(resource.get_path())
+}
+
+/// Get the pressure stall information of all resources by reading the `/proc/pressure` folder. Resources which are not supported by the kernel are not included.
+///
+/// ```rust
+/// use mprober_lib::pressure;
+///
+/// let pressures = pressure::get_all_pressures().unwrap();
+///
+/// for (resource, pressure) in pressures {
+///     if let Some(some) = pressure.some {
+///         println!("{}: {:.2}%", resource.as_str(), some.avg10 * 100.0);
+///     }
+/// }
+/// ```
+pub fn get_all_pressures() -> Result<Vec<(PressureResource, Pressure)>, ScannerError> {
+    let mut pressures = Vec::with_capacity(4);
+
+    for resource in [
+        PressureResource::CPU,
+        PressureResource::Memory,
+        PressureResource::IO,
+        PressureResource::IRQ,
+    ] {
+        match get_pressure(resource) {
+            Ok(pressure) => pressures.push((resource, pressure)),
+            Err(ScannerError::IOError(err)) if err.kind() == ErrorKind::NotFound => (),
+            Err(err) => return Err(err),
+        }
+    }
+
+    Ok(pressures)
+}
+
+/// Get the pressure stall information of a resource in a cgroup by reading the `<resource>.pressure` file in the cgroup (v2) folder such as `/sys/fs/cgroup/system.slice`.
+///
+/// ```rust
+/// use mprober_lib::pressure;
+///
+/// if let Ok(pressure) = pressure::get_cgroup_pressure(
+///     "/sys/fs/cgroup",
+///     pressure::PressureResource::IO,
+/// ) {
+///     println!("{pressure:#?}");
+/// }
+/// ```
+#[inline]
+pub fn get_cgroup_pressure<P: AsRef<Path>>(
+    cgroup_path: P,
+    resource: PressureResource,
+) -> Result<Pressure, ScannerError> {
+    get_pressure_from_path(resource.get_cgroup_path(cgroup_path))
+}
@@ -0,0 +1,135 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, ErrorKind, Write},
+    os::unix::io::AsRawFd,
+    path::Path,
+    time::Duration,
+};
+
+use crate::pressure::PressureResource;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum PressureTriggerKind {
+    /// Some tasks are stalled on the resource.
+    Some,
+    /// All non-idle tasks are stalled on the resource simultaneously.
+    Full,
+}
+
+impl PressureTriggerKind {
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PressureTriggerKind::Some => "some",
+            PressureTriggerKind::Full => "full",
+        }
+    }
+}
+
+/// A PSI trigger. It fires when the stall time of a resource exceeds `stall` within a `window` of time. The trigger is removed when this instance is dropped.
+#[derive(Debug)]
+pub struct PressureTrigger {
+    file: File,
+}
+
+impl PressureTrigger {
+    /// Register a trigger on the system-wide pressure file of a resource. The `window` needs to be between 500 milliseconds and 10 seconds, and the `stall` cannot exceed it. Without `CAP_SYS_RESOURCE`, the `window` needs to be a multiple of 2 seconds (Linux 6.5+).
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    ///
+    /// use mprober_lib::pressure;
+    ///
+    /// // memory stall of 150 ms within 2 seconds
+    /// let trigger = pressure::PressureTrigger::new(
+    ///     pressure::PressureResource::Memory,
+    ///     pressure::PressureTriggerKind::Some,
+    ///     Duration::from_millis(150),
+    ///     Duration::from_secs(2),
+    /// )
+    /// .unwrap();
+    ///
+    /// loop {
+    ///     if trigger.wait(None).unwrap() {
+    ///         println!("memory pressure!");
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    pub fn new(
+        resource: PressureResource,
+        kind: PressureTriggerKind,
+        stall: Duration,
+        window: Duration,
+    ) -> Result<PressureTrigger, io::Error> {
+        Self::new_with_path(resource.get_path(), kind, stall, window)
+    }
+
+    /// Register a trigger on the pressure file of a resource in a cgroup (v2) folder such as `/sys/fs/cgroup/system.slice`.
+    #[inline]
+    pub fn new_with_cgroup<P: AsRef<Path>>(
+        cgroup_path: P,
+        resource: PressureResource,
+        kind: PressureTriggerKind,
+        stall: Duration,
+        window: Duration,
+    ) -> Result<PressureTrigger, io::Error> {
+        Self::new_with_path(resource.get_cgroup_path(cgroup_path), kind, stall, window)
+    }
+
+    /// Register a trigger on a pressure file.
+    pub fn new_with_path<P: AsRef<Path>>(
+        path: P,
+        kind: PressureTriggerKind,
+        stall: Duration,
+        window: Duration,
+    ) -> Result<PressureTrigger, io::Error> {
+        let mut file = OpenOptions::new().read(true).write(true).open(path)?;
+
+        // the kernel requires the whole trigger to be written at once
+        file.write_all(
+            format!("{} {} {}\0", kind.as_str(), stall.as_micros(), window.as_micros()).as_bytes(),
+        )?;
+
+        Ok(PressureTrigger {
+            file,
+        })
+    }
+
+    /// Block the current thread until the trigger fires or the `timeout` elapses. It returns `true` if the trigger fires, or `false` if it times out. A `BrokenPipe` error is returned if the monitored cgroup is removed.
+    pub fn wait(&self, timeout: Option<Duration>) -> Result<bool, io::Error> {
+        let timeout = match timeout {
+            Some(timeout) => timeout.as_millis().min(libc::c_int::MAX as u128) as libc::c_int,
+            None => -1,
+        };
+
+        let mut poll_fd =
+            libc::pollfd {
+                fd: self.file.as_raw_fd(), events: libc::POLLPRI, revents: 0
+            };
+
+        loop {
+            let result = unsafe { libc::poll(&mut poll_fd, 1, timeout) };
+
+            if result < 0 {
+                let err = io::Error::last_os_error();
+
+                if err.kind() == ErrorKind::Interrupted {
+                    continue;
+                }
+
+                return Err(err);
+            }
+
+            if result == 0 {
+                return Ok(false);
+            }
+
+            if poll_fd.revents & libc::POLLERR != 0 {
+                return Err(ErrorKind::BrokenPipe.into());
+            }
+
+            return Ok(poll_fd.revents & libc::POLLPRI != 0);
+        }
+    }
+}
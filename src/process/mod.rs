@@ -1,6 +1,7 @@
 #[allow(clippy::module_inception)]
 mod process;
 mod process_filter;
+mod process_sched_stat;
 mod process_stat;
 mod process_state;
 mod process_status;
@@ -8,6 +9,7 @@ mod process_time_stat;
 
 pub use process::*;
 pub use process_filter::*;
+pub use process_sched_stat::*;
 pub use process_stat::*;
 pub use process_state::*;
 pub use process_status::*;
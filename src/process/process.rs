@@ -13,10 +13,10 @@ use chrono::prelude::*;
 
 use crate::{
     btime::get_btime,
-    cpu::get_average_cpu_stat,
+    cpu::{get_average_cpu_stat, SchedLatency},
     process::{
-        get_process_stat, get_process_status, get_process_time_stat, ProcessFilter, ProcessStat,
-        ProcessState, ProcessTimeStat,
+        get_process_sched_stat, get_process_stat, get_process_status, get_process_time_stat,
+        ProcessFilter, ProcessStat, ProcessState, ProcessTimeStat,
     },
     scanner_rust::ScannerError,
 };
@@ -353,3 +353,51 @@ pub fn get_processes_with_cpu_utilization_in_percentage(
 
     Ok(processes_with_cpu_percentage)
 }
+
+/// Get process information by reading files in the `/proc/PID` folders and measure the run-queue latency by reading the `/proc/PID/schedstat` files within a specific time interval. Processes which exit during the interval are not included.
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use mprober_lib::process;
+///
+/// let processes_with_sched_latency =
+///     process::get_processes_with_sched_latency(
+///         &process::ProcessFilter::default(),
+///         Duration::from_millis(100),
+///     )
+///     .unwrap();
+///
+/// for (process, sched_latency) in processes_with_sched_latency {
+///     println!("{}: {:?}", process.pid, sched_latency.average_wait);
+/// }
+/// ```
+pub fn get_processes_with_sched_latency(
+    process_filter: &ProcessFilter,
+    interval: Duration,
+) -> Result<Vec<(Process, SchedLatency)>, ScannerError> {
+    let processes_with_stat = get_processes_with_stat(process_filter)?;
+
+    let mut processes_with_sched_stat = Vec::with_capacity(processes_with_stat.len());
+
+    for (process, _) in processes_with_stat {
+        if let Ok(process_sched_stat) = get_process_sched_stat(process.pid) {
+            processes_with_sched_stat.push((process, process_sched_stat));
+        }
+    }
+
+    sleep(interval);
+
+    let mut processes_with_sched_latency = Vec::with_capacity(processes_with_sched_stat.len());
+
+    for (process, pre_process_sched_stat) in processes_with_sched_stat {
+        if let Ok(process_sched_stat) = get_process_sched_stat(process.pid) {
+            let sched_latency =
+                pre_process_sched_stat.compute_sched_latency(&process_sched_stat, interval);
+
+            processes_with_sched_latency.push((process, sched_latency));
+        }
+    }
+
+    Ok(processes_with_sched_latency)
+}
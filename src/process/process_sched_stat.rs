@@ -0,0 +1,82 @@
+use std::{io::ErrorKind, path::Path, time::Duration};
+
+use crate::{
+    cpu::SchedLatency,
+    scanner_rust::{generic_array::typenum::U64, ScannerAscii, ScannerError},
+};
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct ProcessSchedStat {
+    /// The total time spent running, in nanoseconds.
+    pub run_time:   u64,
+    /// The total time spent waiting on a run queue, in nanoseconds.
+    pub wait_time:  u64,
+    /// The number of timeslices run.
+    pub timeslices: u64,
+}
+
+impl ProcessSchedStat {
+    /// Compute the run-queue latency between two `ProcessSchedStat` instances at different time.
+    ///
+    /// ```rust
+    /// use std::{thread::sleep, time::Duration};
+    ///
+    /// use mprober_lib::process;
+    ///
+    /// if let Ok(pre_process_sched_stat) = process::get_process_sched_stat(1) {
+    ///     let interval = Duration::from_millis(100);
+    ///
+    ///     sleep(interval);
+    ///
+    ///     let process_sched_stat = process::get_process_sched_stat(1).unwrap();
+    ///
+    ///     let sched_latency = pre_process_sched_stat
+    ///         .compute_sched_latency(&process_sched_stat, interval);
+    ///
+    ///     println!("{:?}", sched_latency.average_wait);
+    /// }
+    /// ```
+    #[inline]
+    pub fn compute_sched_latency(
+        &self,
+        process_sched_stat_after_this: &ProcessSchedStat,
+        interval: Duration,
+    ) -> SchedLatency {
+        SchedLatency::from_increments(
+            process_sched_stat_after_this.wait_time.saturating_sub(self.wait_time),
+            process_sched_stat_after_this.timeslices.saturating_sub(self.timeslices),
+            interval,
+        )
+    }
+}
+
+/// Get the scheduler statistics of a process by reading a file in the format of `/proc/PID/schedstat`.
+pub fn get_process_sched_stat_from_path<P: AsRef<Path>>(
+    path: P,
+) -> Result<ProcessSchedStat, ScannerError> {
+    let mut sc: ScannerAscii<_, U64> = ScannerAscii::scan_path2(path)?;
+
+    let run_time = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+    let wait_time = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+    let timeslices = sc.next_u64()?.ok_or(ErrorKind::UnexpectedEof)?;
+
+    Ok(ProcessSchedStat {
+        run_time,
+        wait_time,
+        timeslices,
+    })
+}
+
+/// Get the scheduler statistics of a specific process found by ID by reading the `/proc/PID/schedstat` file. The kernel needs to be built with `CONFIG_SCHED_INFO`, or a `NotFound` error is returned.
+///
+/// ```rust
+/// use mprober_lib::process;
+///
+/// if let Ok(process_sched_stat) = process::get_process_sched_stat(1) {
+///     println!("{process_sched_stat:#?}");
+/// }
+/// ```
+#[inline]
+pub fn get_process_sched_stat(pid: u32) -> Result<ProcessSchedStat, ScannerError> {
+    get_process_sched_stat_from_path(Path::new("/proc").join(pid.to_string()).join("schedstat"))
+}
@@ -41,20 +41,13 @@ pub struct ProcessStat {
     pub rss_anon:     usize,
 }
 
-/// Get the stat of a specific process found by ID by reading the `/proc/PID/stat` file and the `/proc/PID/statm` file.
-///
-/// ```rust
-/// use mprober_lib::process;
-///
-/// let process_stat = process::get_process_stat(1).unwrap();
-///
-/// println!("{process_stat:#?}");
-/// ```
-pub fn get_process_stat(pid: u32) -> Result<ProcessStat, ScannerError> {
+/// Get the stat of a process by reading a file in the format of `/proc/PID/stat` and a file in the format of `/proc/PID/statm`.
+pub fn get_process_stat_from_paths<P: AsRef<Path>, M: AsRef<Path>>(
+    stat_path: P,
+    statm_path: M,
+) -> Result<ProcessStat, ScannerError> {
     let mut stat = ProcessStat::default();
 
-    let stat_path = Path::new("/proc").join(pid.to_string()).join("stat");
-
     let mut sc: Scanner<_, U192> = Scanner::scan_path2(stat_path)?;
 
     sc.drop_next()?.ok_or(ErrorKind::UnexpectedEof)?;
@@ -124,8 +117,6 @@ pub fn get_process_stat(pid: u32) -> Result<ProcessStat, ScannerError> {
 
     drop(sc);
 
-    let statm_path = Path::new("/proc").join(pid.to_string()).join("statm");
-
     let mut sc: Scanner<_, U32> = Scanner::scan_path2(statm_path)?;
 
     for _ in 0..2 {
@@ -134,7 +125,24 @@ pub fn get_process_stat(pid: u32) -> Result<ProcessStat, ScannerError> {
 
     stat.shared = sc.next_usize()?.ok_or(ErrorKind::UnexpectedEof)? * get_page_size();
 
-    stat.rss_anon = stat.rss - stat.shared;
+    // `stat` and `statm` are not read atomically, so `shared` can be larger than `rss` for a short-lived change
+    stat.rss_anon = stat.rss.saturating_sub(stat.shared);
 
     Ok(stat)
 }
+
+/// Get the stat of a specific process found by ID by reading the `/proc/PID/stat` file and the `/proc/PID/statm` file.
+///
+/// ```rust
+/// use mprober_lib::process;
+///
+/// let process_stat = process::get_process_stat(1).unwrap();
+///
+/// println!("{process_stat:#?}");
+/// ```
+#[inline]
+pub fn get_process_stat(pid: u32) -> Result<ProcessStat, ScannerError> {
+    let process_path = Path::new("/proc").join(pid.to_string());
+
+    get_process_stat_from_paths(process_path.join("stat"), process_path.join("statm"))
+}
@@ -0,0 +1,491 @@
+use mprober_lib::cpu;
+
+#[test]
+fn get_cpus_x86_64() {
+    let cpus =
+        cpu::get_cpus_from_paths("tests/data/cpu/x86_64/cpuinfo", "tests/data/cpu/x86_64/sys")
+            .unwrap();
+
+    assert_eq!(1, cpus.len());
+
+    let cpu = &cpus[0];
+
+    assert_eq!(0, cpu.physical_id);
+    assert_eq!("Intel(R) Core(TM) i7-7700 CPU @ 3.60GHz", cpu.model_name);
+    assert_eq!(vec![3400.0, 3400.123, 1200.0, 3399.999], cpu.cpus_mhz);
+    assert_eq!(4, cpu.siblings);
+    assert_eq!(2, cpu.cpu_cores);
+}
+
+#[test]
+fn get_logical_cpus_x86_64() {
+    let logical_cpus = cpu::get_logical_cpus_from_path("tests/data/cpu/x86_64/cpuinfo").unwrap();
+
+    assert_eq!(4, logical_cpus.len());
+
+    let logical_cpu = &logical_cpus[3];
+
+    assert_eq!(3, logical_cpu.processor);
+    assert_eq!(Some("GenuineIntel"), logical_cpu.vendor_id.as_deref());
+    assert_eq!(Some(6), logical_cpu.cpu_family);
+    assert_eq!(Some(158), logical_cpu.model);
+    assert_eq!(Some(9), logical_cpu.stepping);
+    assert_eq!(Some(0xF4), logical_cpu.microcode);
+    assert_eq!(Some(8192 * 1024), logical_cpu.cache_size);
+    assert_eq!(Some(1), logical_cpu.core_id);
+    assert_eq!(Some(3), logical_cpu.apicid);
+    assert_eq!(Some(7200.0), logical_cpu.bogomips);
+    assert_eq!(
+        Some(cpu::AddressSizes {
+            physical_bits: 39, virtual_bits: 48
+        }),
+        logical_cpu.address_sizes
+    );
+    assert!(logical_cpu.has_flag("avx2"));
+    assert!(!logical_cpu.has_flag("avx512f"));
+}
+
+#[test]
+fn get_cpus_aarch64() {
+    let cpus =
+        cpu::get_cpus_from_paths("tests/data/cpu/aarch64/cpuinfo", "tests/data/cpu/aarch64/sys")
+            .unwrap();
+
+    assert_eq!(1, cpus.len());
+
+    let cpu = &cpus[0];
+
+    assert_eq!(0, cpu.physical_id);
+    assert_eq!("ARM Cortex-A72", cpu.model_name);
+    // the frequency of cpu3 is `<unknown>`
+    assert_eq!(vec![1500.0; 3], cpu.cpus_mhz);
+    assert_eq!(4, cpu.siblings);
+    assert_eq!(4, cpu.cpu_cores);
+
+    let logical_cpus = cpu::get_logical_cpus_from_path("tests/data/cpu/aarch64/cpuinfo").unwrap();
+
+    assert!(logical_cpus[0].has_flag("asimd"));
+    assert_eq!(Some(108.0), logical_cpus[0].bogomips);
+}
+
+#[test]
+fn get_cpus_armv7() {
+    let cpus = cpu::get_cpus_from_paths("tests/data/cpu/armv7/cpuinfo", "tests/data/cpu/armv7/sys")
+        .unwrap();
+
+    assert_eq!(1, cpus.len());
+
+    let cpu = &cpus[0];
+
+    assert_eq!("ARMv7 Processor rev 4 (v7l)", cpu.model_name);
+    assert!(cpu.cpus_mhz.is_empty());
+    assert_eq!(2, cpu.siblings);
+    assert_eq!(2, cpu.cpu_cores);
+
+    let logical_cpus = cpu::get_logical_cpus_from_path("tests/data/cpu/armv7/cpuinfo").unwrap();
+
+    assert_eq!(2, logical_cpus.len());
+    assert_eq!(Some("BCM2835"), logical_cpus[1].hardware.as_deref());
+}
+
+#[test]
+fn get_cpus_riscv64() {
+    let cpus =
+        cpu::get_cpus_from_paths("tests/data/cpu/riscv64/cpuinfo", "tests/data/cpu/riscv64/sys")
+            .unwrap();
+
+    assert_eq!(1, cpus.len());
+
+    let cpu = &cpus[0];
+
+    assert_eq!(0, cpu.physical_id);
+    assert_eq!("sifive,u74-mc", cpu.model_name);
+    assert!(cpu.cpus_mhz.is_empty());
+    assert_eq!(4, cpu.siblings);
+    assert_eq!(4, cpu.cpu_cores);
+}
+
+#[test]
+fn get_cpus_hypervisor() {
+    let cpus = cpu::get_cpus_from_paths(
+        "tests/data/cpu/hypervisor/cpuinfo",
+        "tests/data/cpu/hypervisor/sys",
+    )
+    .unwrap();
+
+    assert_eq!(1, cpus.len());
+
+    let cpu = &cpus[0];
+
+    assert_eq!("AMD EPYC 7R32", cpu.model_name);
+    assert_eq!(vec![2799.998; 2], cpu.cpus_mhz);
+    assert_eq!(2, cpu.siblings);
+    assert_eq!(2, cpu.cpu_cores);
+}
+
+#[test]
+fn parse_cpu_list() {
+    assert_eq!(Vec::<usize>::new(), cpu::parse_cpu_list("").unwrap());
+    assert_eq!(vec![0], cpu::parse_cpu_list("0\n").unwrap());
+    assert_eq!(vec![0, 1, 2, 3, 8, 10, 11], cpu::parse_cpu_list("0-3,8,10-11").unwrap());
+    assert!(cpu::parse_cpu_list("3-1").is_err());
+}
+
+#[test]
+fn get_all_cpus_stat() {
+    // cpu1 is offline
+    let all_cpus_stat = cpu::get_all_cpus_stat_from_path("tests/data/cpu/stat/stat", true).unwrap();
+
+    assert_eq!(
+        vec![None, Some(0), Some(2), Some(3)],
+        all_cpus_stat.iter().map(|cpu_stat| cpu_stat.cpu).collect::<Vec<_>>()
+    );
+
+    assert_eq!(301844, all_cpus_stat[0].user);
+    assert_eq!(99874, all_cpus_stat[2].user);
+    assert_eq!(412, all_cpus_stat[2].softirq);
+    assert_eq!(1573290, all_cpus_stat[3].idle);
+
+    let all_cpus_stat =
+        cpu::get_all_cpus_stat_from_path("tests/data/cpu/stat/stat", false).unwrap();
+
+    assert_eq!(
+        vec![Some(0), Some(2), Some(3)],
+        all_cpus_stat.iter().map(|cpu_stat| cpu_stat.cpu).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn compute_breakdown() {
+    let pre_cpu_stat = cpu::CPUStat {
+        cpu: Some(0),
+        ..cpu::CPUStat::default()
+    };
+
+    let cpu_stat = cpu::CPUStat {
+        cpu: Some(0),
+        user: 50,
+        nice: 10,
+        system: 10,
+        idle: 20,
+        steal: 10,
+        guest: 30,
+        guest_nice: 10,
+        ..cpu::CPUStat::default()
+    };
+
+    let breakdown = pre_cpu_stat.compute_breakdown(&cpu_stat);
+
+    assert_eq!(Some(0), breakdown.cpu);
+    assert_eq!(0.2, breakdown.user);
+    assert_eq!(0.0, breakdown.nice);
+    assert_eq!(0.3, breakdown.guest);
+    assert_eq!(0.1, breakdown.guest_nice);
+    assert_eq!(0.1, breakdown.steal);
+
+    let total = breakdown.user
+        + breakdown.nice
+        + breakdown.system
+        + breakdown.idle
+        + breakdown.iowait
+        + breakdown.irq
+        + breakdown.softirq
+        + breakdown.steal
+        + breakdown.guest
+        + breakdown.guest_nice;
+
+    assert!((total - 1.0).abs() < 1e-9);
+
+    let idle_breakdown = cpu_stat.compute_breakdown(&cpu_stat);
+
+    assert_eq!(0.0, idle_breakdown.idle);
+}
+
+#[test]
+fn get_cpu_vulnerabilities() {
+    let cpu_vulnerabilities = cpu::get_cpu_vulnerabilities_from_paths(
+        "tests/data/cpu/vulnerabilities",
+        "tests/data/cpu/cmdline",
+    )
+    .unwrap();
+
+    assert_eq!(Some("auto,nosmt"), cpu_vulnerabilities.mitigations.as_deref());
+    assert!(cpu_vulnerabilities.is_vulnerable());
+
+    let vulnerabilities = &cpu_vulnerabilities.vulnerabilities;
+
+    assert_eq!(7, vulnerabilities.len());
+
+    assert_eq!("itlb_multihit", vulnerabilities[0].name);
+    assert_eq!(cpu::CPUVulnerabilityStatus::Mitigated, vulnerabilities[0].status);
+    assert_eq!(Some("KVM"), vulnerabilities[0].scope.as_deref());
+    assert_eq!(Some("VMX disabled"), vulnerabilities[0].detail.as_deref());
+
+    assert_eq!("l1tf", vulnerabilities[1].name);
+    assert_eq!(cpu::CPUVulnerabilityStatus::NotAffected, vulnerabilities[1].status);
+    assert_eq!(None, vulnerabilities[1].detail);
+
+    assert_eq!("mds", vulnerabilities[2].name);
+    assert_eq!(cpu::CPUVulnerabilityStatus::Vulnerable, vulnerabilities[2].status);
+    assert_eq!(
+        Some("Clear CPU buffers attempted, no microcode; SMT vulnerable"),
+        vulnerabilities[2].detail.as_deref()
+    );
+
+    assert_eq!("meltdown", vulnerabilities[3].name);
+    assert_eq!(cpu::CPUVulnerabilityStatus::Mitigated, vulnerabilities[3].status);
+    assert_eq!(None, vulnerabilities[3].scope);
+    assert_eq!(Some("PTI"), vulnerabilities[3].detail.as_deref());
+    assert!(!vulnerabilities[3].is_partially_vulnerable());
+
+    assert_eq!(cpu::CPUVulnerabilityStatus::Vulnerable, vulnerabilities[4].status);
+    assert_eq!(None, vulnerabilities[4].detail);
+
+    assert_eq!("spectre_v2", vulnerabilities[5].name);
+    assert_eq!(cpu::CPUVulnerabilityStatus::Mitigated, vulnerabilities[5].status);
+    assert!(vulnerabilities[5].is_partially_vulnerable());
+
+    assert_eq!("srbds", vulnerabilities[6].name);
+    assert_eq!(cpu::CPUVulnerabilityStatus::Unknown, vulnerabilities[6].status);
+    assert_eq!(None, vulnerabilities[6].scope);
+    assert_eq!(Some("Dependent on hypervisor status"), vulnerabilities[6].detail.as_deref());
+
+    let cpu_vulnerabilities = cpu::get_cpu_vulnerabilities_from_paths(
+        "tests/data/cpu/vulnerabilities_kvm",
+        "tests/data/cpu/cmdline",
+    )
+    .unwrap();
+
+    assert!(cpu_vulnerabilities.is_vulnerable());
+
+    let vulnerabilities = &cpu_vulnerabilities.vulnerabilities;
+
+    assert_eq!("itlb_multihit", vulnerabilities[0].name);
+    assert_eq!(cpu::CPUVulnerabilityStatus::Vulnerable, vulnerabilities[0].status);
+    assert_eq!(Some("KVM"), vulnerabilities[0].scope.as_deref());
+    assert_eq!(None, vulnerabilities[0].detail);
+
+    assert_eq!("mds", vulnerabilities[1].name);
+    assert_eq!(cpu::CPUVulnerabilityStatus::Mitigated, vulnerabilities[1].status);
+    assert!(vulnerabilities[1].is_partially_vulnerable());
+}
+
+#[test]
+fn get_cpus_idle() {
+    let cpus_idle = cpu::get_cpus_idle_from_path("tests/data/cpu/cpuidle").unwrap();
+
+    assert_eq!(1, cpus_idle.len());
+
+    let states = &cpus_idle[0].states;
+
+    assert_eq!(3, states.len());
+    assert_eq!("C6", states[2].name);
+    assert_eq!("MWAIT 0x20", states[2].description);
+    assert_eq!(85, states[2].latency);
+    assert_eq!(200, states[2].residency);
+    assert_eq!(900, states[2].usage);
+    assert_eq!(70000000, states[2].time);
+    assert!(states[2].disabled);
+    assert!(!states[1].disabled);
+
+    let mut after = cpus_idle[0].clone();
+
+    after.states[1].time += 250000;
+
+    let residency =
+        cpus_idle[0].compute_residency_in_percentage(&after, std::time::Duration::from_secs(1));
+
+    assert_eq!(vec![0.0, 0.25, 0.0], residency);
+}
+
+#[test]
+fn get_cpus_sched_stat() {
+    let cpus_sched_stat = cpu::get_cpus_sched_stat_from_path("tests/data/cpu/schedstat").unwrap();
+
+    assert_eq!(2, cpus_sched_stat.len());
+
+    let cpu_sched_stat = &cpus_sched_stat[1];
+
+    assert_eq!(1, cpu_sched_stat.cpu);
+    assert_eq!(5, cpu_sched_stat.yield_count);
+    assert_eq!(1000, cpu_sched_stat.schedule_count);
+    assert_eq!(300, cpu_sched_stat.wakeup_local_count);
+    assert_eq!(987654321, cpu_sched_stat.run_time);
+    assert_eq!(1000000, cpu_sched_stat.wait_time);
+    assert_eq!(500, cpu_sched_stat.timeslices);
+
+    let mut after = cpu_sched_stat.clone();
+
+    after.wait_time += 50_000_000;
+    after.timeslices += 100;
+
+    let sched_latency =
+        cpu_sched_stat.compute_sched_latency(&after, std::time::Duration::from_millis(100));
+
+    assert_eq!(std::time::Duration::from_micros(500), sched_latency.average_wait);
+    assert_eq!(0.5, sched_latency.wait_rate);
+    assert_eq!(100, sched_latency.timeslices);
+}
+
+#[test]
+fn get_cpu_core_classes() {
+    use cpu::CPUCoreClass::{Efficiency, Performance, Unknown};
+
+    let classes = cpu::get_cpu_core_classes_from_path("tests/data/cpu/core_class/types").unwrap();
+
+    assert_eq!(
+        vec![Performance, Performance, Efficiency, Efficiency],
+        classes.into_values().collect::<Vec<_>>()
+    );
+
+    // the PMUs take precedence over `cpu_capacity`
+    let classes = cpu::get_cpu_core_classes_from_path("tests/data/cpu/core_class/pmu").unwrap();
+
+    assert_eq!(
+        vec![Performance, Performance, Performance, Performance, Efficiency, Efficiency],
+        classes.into_values().collect::<Vec<_>>()
+    );
+
+    // `cpu_capacity` takes precedence over `cpuinfo_max_freq`
+    let classes =
+        cpu::get_cpu_core_classes_from_path("tests/data/cpu/core_class/capacity").unwrap();
+
+    assert_eq!(
+        vec![Performance, Performance, Efficiency, Efficiency],
+        classes.into_values().collect::<Vec<_>>()
+    );
+
+    // `cpu_capacity` is not available on every CPU, and `4300000 / 5000000` is above the 0.8 ratio
+    let classes =
+        cpu::get_cpu_core_classes_from_path("tests/data/cpu/core_class/frequency").unwrap();
+
+    assert_eq!(vec![Performance; 4], classes.into_values().collect::<Vec<_>>());
+
+    let classes = cpu::get_cpu_core_classes_from_path("tests/data/cpu/core_class/unknown").unwrap();
+
+    assert_eq!(vec![(0, Unknown), (1, Unknown)], classes.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn get_cpu_caches() {
+    use cpu::CPUCacheType::{Data, Instruction, Unified};
+
+    let cpu_caches = cpu::get_cpu_caches_from_path("tests/data/cpu/cache").unwrap();
+
+    assert_eq!(1, cpu_caches.len());
+
+    let l3 = &cpu_caches[0];
+
+    assert_eq!(3, l3.level);
+    assert_eq!(Unified, l3.cache_type);
+    assert_eq!(12 * 1024 * 1024, l3.size);
+    assert_eq!(Some(64), l3.line_size);
+    assert_eq!(Some(12), l3.ways_of_associativity);
+    assert_eq!(vec![0, 1, 2, 3], l3.shared_cpus);
+    assert_eq!(2, l3.children.len());
+
+    for (l2, cpus) in l3.children.iter().zip([vec![0, 1], vec![2, 3]]) {
+        assert_eq!(2, l2.level);
+        assert_eq!(Unified, l2.cache_type);
+        assert_eq!(1280 * 1024, l2.size);
+        assert_eq!(cpus, l2.shared_cpus);
+        assert_eq!(2, l2.children.len());
+
+        let l1d = &l2.children[0];
+
+        assert_eq!(1, l1d.level);
+        assert_eq!(Data, l1d.cache_type);
+        assert_eq!(48 * 1024, l1d.size);
+        assert_eq!(cpus, l1d.shared_cpus);
+        assert!(l1d.children.is_empty());
+
+        let l1i = &l2.children[1];
+
+        assert_eq!(1, l1i.level);
+        assert_eq!(Instruction, l1i.cache_type);
+        assert_eq!(32 * 1024, l1i.size);
+        assert_eq!(cpus, l1i.shared_cpus);
+        assert!(l1i.children.is_empty());
+    }
+}
+
+#[test]
+fn get_cpus_frequency() {
+    let cpus_frequency = cpu::get_cpus_frequency_from_path("tests/data/cpu/cpufreq").unwrap();
+
+    assert_eq!(3, cpus_frequency.len());
+
+    let cpu0 = &cpus_frequency[0];
+
+    assert_eq!(0, cpu0.cpu);
+    assert_eq!(Some(3400000), cpu0.current);
+    assert_eq!(Some(800000), cpu0.min);
+    assert_eq!(Some(4700000), cpu0.hardware_max);
+    assert_eq!(Some(2100000), cpu0.base);
+    assert_eq!(Some("powersave"), cpu0.governor.as_deref());
+    assert_eq!(vec!["performance", "powersave"], cpu0.available_governors);
+    assert_eq!(Some("intel_pstate"), cpu0.driver.as_deref());
+    assert_eq!(Some("balance_performance"), cpu0.energy_performance_preference.as_deref());
+    // `intel_pstate/no_turbo` is `0`
+    assert_eq!(Some(true), cpu0.boost);
+
+    let cpu1 = &cpus_frequency[1];
+
+    // `<unknown>`
+    assert_eq!(None, cpu1.current);
+    assert_eq!(Some(4700000), cpu1.max);
+    assert_eq!(None, cpu1.base);
+    assert!(cpu1.available_governors.is_empty());
+    assert_eq!(Some(false), cpu1.boost);
+
+    // no `cpufreq` folder
+    let cpu2 = &cpus_frequency[2];
+
+    assert_eq!(None, cpu2.current);
+    assert_eq!(None, cpu2.governor);
+    assert_eq!(Some(true), cpu2.boost);
+}
+
+#[test]
+fn get_cpu_topology() {
+    let cpu_topology = cpu::get_cpu_topology_from_path("tests/data/cpu/topology").unwrap();
+
+    assert_eq!(vec![0, 1, 2], cpu_topology.online);
+    assert_eq!(vec![3, 4, 5, 6, 7], cpu_topology.offline);
+    assert_eq!(vec![0, 1, 2, 3], cpu_topology.present);
+    assert_eq!(8, cpu_topology.possible.len());
+
+    assert_eq!(2, cpu_topology.nodes.len());
+    assert_eq!(vec![2, 3], cpu_topology.nodes[1].cpus);
+
+    assert_eq!(4, cpu_topology.cpus.len());
+
+    let cpu1 = &cpu_topology.cpus[1];
+
+    assert_eq!(1, cpu1.cpu);
+    assert!(cpu1.online);
+    assert_eq!(Some(0), cpu1.package_id);
+    assert_eq!(Some(0), cpu1.die_id);
+    assert_eq!(Some(0), cpu1.core_id);
+    assert_eq!(Some(0), cpu1.node);
+    assert_eq!(cpu::CPUCoreClass::Performance, cpu1.core_class);
+    assert_eq!(vec![0, 1], cpu1.thread_siblings);
+    assert_eq!(vec![0, 1], cpu1.core_siblings);
+    assert_eq!(vec![0, 1], cpu1.die_cpus);
+
+    let cpu2 = &cpu_topology.cpus[2];
+
+    assert_eq!(Some(1), cpu2.package_id);
+    assert_eq!(Some(1), cpu2.node);
+    assert_eq!(vec![2, 3], cpu2.thread_siblings);
+
+    // offline, and the kernel hides its topology
+    let cpu3 = &cpu_topology.cpus[3];
+
+    assert!(!cpu3.online);
+    assert_eq!(None, cpu3.package_id);
+    assert_eq!(None, cpu3.core_id);
+    assert_eq!(Some(1), cpu3.node);
+    assert_eq!(cpu::CPUCoreClass::Unknown, cpu3.core_class);
+    assert!(cpu3.thread_siblings.is_empty());
+}
@@ -0,0 +1,65 @@
+use std::time::Duration;
+
+use mprober_lib::interrupt;
+
+#[test]
+fn get_interrupts() {
+    let interrupts = interrupt::get_interrupts_from_paths(
+        "tests/data/interrupt/interrupts",
+        "tests/data/interrupt/irq",
+    )
+    .unwrap();
+
+    assert_eq!(8, interrupts.len());
+
+    let irq_11 = &interrupts[1];
+
+    assert_eq!(Some(11), irq_11.get_irq_number());
+    assert_eq!(Some(&35), irq_11.counts.get(&2));
+    assert_eq!(Some("IO-APIC"), irq_11.chip.as_deref());
+    assert_eq!(Some("11"), irq_11.hwirq.as_deref());
+    assert_eq!(Some("fasteoi"), irq_11.trigger_type.as_deref());
+    assert_eq!(vec!["i801_smbus", "rtc0"], irq_11.devices);
+    assert_eq!(Some(vec![2]), irq_11.affinity);
+
+    let irq_27 = &interrupts[3];
+
+    assert_eq!(Some("GICv3"), irq_27.chip.as_deref());
+    assert_eq!(Some("Level"), irq_27.trigger_type.as_deref());
+    assert_eq!(vec!["arch_timer"], irq_27.devices);
+    assert_eq!(None, irq_27.affinity);
+
+    let nmi = &interrupts[4];
+
+    assert_eq!(None, nmi.get_irq_number());
+    assert_eq!(11, nmi.total);
+    assert_eq!(Some("Non-maskable interrupts"), nmi.description.as_deref());
+
+    let err = &interrupts[6];
+
+    assert!(err.counts.is_empty());
+    assert_eq!(3, err.total);
+}
+
+#[test]
+fn get_softirqs() {
+    let softirqs = interrupt::get_softirqs_from_path("tests/data/interrupt/softirqs").unwrap();
+
+    assert_eq!(4, softirqs.len());
+
+    let net_rx = &softirqs[3];
+
+    assert_eq!("NET_RX", net_rx.name);
+    assert_eq!(102384, net_rx.total);
+
+    let mut after = net_rx.clone();
+
+    after.counts.insert(1, 100999);
+    after.total += 1000;
+
+    let rate = net_rx.compute_rate(&after, Duration::from_secs(2));
+
+    assert_eq!(500.0, rate.total);
+    assert_eq!(Some(&0.0), rate.per_cpu.get(&0));
+    assert_eq!(Some(&500.0), rate.per_cpu.get(&1));
+}
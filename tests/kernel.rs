@@ -0,0 +1,27 @@
+use std::time::Duration;
+
+use mprober_lib::kernel;
+
+#[test]
+fn get_kernel_stat() {
+    let kernel_stat = kernel::get_kernel_stat_from_path("tests/data/kernel/stat").unwrap();
+
+    assert_eq!(181285, kernel_stat.interrupts);
+    assert_eq!(1007586, kernel_stat.context_switches);
+    assert_eq!(1792343422, kernel_stat.btime);
+    assert_eq!(20198, kernel_stat.processes);
+    assert_eq!(3, kernel_stat.procs_running);
+    assert_eq!(1, kernel_stat.procs_blocked);
+    assert_eq!(124624, kernel_stat.softirqs);
+    assert_eq!(vec![0, 45237, 3, 2189, 0, 0, 10, 0, 0, 77185], kernel_stat.softirqs_by_type);
+
+    let after = kernel::KernelStat {
+        processes: kernel_stat.processes + 100,
+        ..kernel_stat.clone()
+    };
+
+    let rate = kernel_stat.compute_rate(&after, Duration::from_secs(2));
+
+    assert_eq!(50.0, rate.forks);
+    assert_eq!(0.0, rate.context_switches);
+}
@@ -0,0 +1,281 @@
+use std::time::Duration;
+
+use mprober_lib::memory;
+
+#[test]
+fn get_mem_info() {
+    let mem_info = memory::get_mem_info_from_path("tests/data/memory/meminfo").unwrap();
+
+    assert_eq!(Some(8000000 * 1024), mem_info.mem_total);
+    assert_eq!(Some(500000 * 1024), mem_info.active_anon);
+    assert_eq!(Some(1900000 * 1024), mem_info.inactive_file);
+    assert_eq!(Some(3000000 * 1024), mem_info.cached);
+    assert_eq!(Some(10000 * 1024), mem_info.swap_cached);
+    assert_eq!(Some(4), mem_info.huge_pages_total);
+    assert_eq!(Some(2048 * 1024), mem_info.hugepagesize);
+    assert_eq!(None, mem_info.zswap);
+    assert_eq!(Some(&(12 * 1024)), mem_info.others.get("VendorMem"));
+    assert_eq!(Some(&7), mem_info.others.get("VendorCount"));
+}
+
+#[test]
+fn free() {
+    let free = memory::free_from_path("tests/data/memory/meminfo").unwrap();
+
+    assert_eq!(8000000 * 1024, free.mem.total);
+    assert_eq!(3400000 * 1024, free.mem.cache);
+    assert_eq!(3400000 * 1024, free.mem.used);
+    assert_eq!(5000000 * 1024, free.mem.available);
+    assert_eq!(90000 * 1024, free.swap.used);
+}
+
+#[test]
+fn free_old_kernel() {
+    let free = memory::free_from_path("tests/data/memory/meminfo_old").unwrap();
+
+    assert_eq!(800000 * 1024, free.mem.cache);
+    assert_eq!(600000 * 1024, free.mem.used);
+    assert_eq!(1400000 * 1024, free.mem.available);
+    assert_eq!(0, free.mem.shared);
+    assert_eq!(0, free.swap.used);
+}
+
+#[test]
+fn free_saturating() {
+    let free = memory::free_from_path("tests/data/memory/meminfo_underflow").unwrap();
+
+    assert_eq!(400000 * 1024, free.mem.cache);
+    assert_eq!(0, free.mem.used);
+    assert_eq!(0, free.swap.used);
+}
+
+#[test]
+fn free_with_zfs_arc() {
+    let zfs_arc = memory::get_zfs_arc_from_path("tests/data/memory/arcstats").unwrap().unwrap();
+
+    assert_eq!(1536 * 1024 * 1024, zfs_arc.size);
+    assert_eq!(2048 * 1024 * 1024, zfs_arc.target);
+    assert_eq!(1024 * 1024 * 1024, zfs_arc.get_reclaimable_size());
+
+    assert_eq!(None, memory::get_zfs_arc_from_path("tests/data/memory/nonexistent").unwrap());
+
+    let free = memory::free_with_zfs_arc_from_paths(
+        "tests/data/memory/meminfo",
+        "tests/data/memory/arcstats",
+    )
+    .unwrap();
+
+    assert_eq!(3400000 * 1024 - 1024 * 1024 * 1024, free.mem.used);
+    assert_eq!(3400000 * 1024 + 1024 * 1024 * 1024, free.mem.cache);
+    assert_eq!(5000000 * 1024 + 1024 * 1024 * 1024, free.mem.available);
+}
+
+#[test]
+fn get_vmstat() {
+    let vmstat = memory::get_vmstat_from_path("tests/data/memory/vmstat").unwrap();
+
+    assert_eq!(Some(10), vmstat.pswpin);
+    assert_eq!(Some(300), vmstat.pgmajfault);
+    assert_eq!(Some(600), vmstat.pgscan);
+    assert_eq!(Some(150), vmstat.pgsteal);
+    assert_eq!(Some(7), vmstat.allocstall);
+    assert_eq!(Some(1), vmstat.oom_kill);
+    assert_eq!(None, vmstat.thp_split_page);
+    assert_eq!(Some(12), vmstat.get("nr_dirty"));
+
+    let mut after = vmstat.clone();
+
+    after.pgmajfault = Some(500);
+    after.counters.insert(String::from("pgmajfault"), 500);
+    after.counters.insert(String::from("nr_dirty"), 100);
+
+    let vmstat_rate = vmstat.compute_rate(&after, Duration::from_secs(2));
+
+    assert_eq!(100.0, vmstat_rate.pgmajfault);
+    assert_eq!(0.0, vmstat_rate.pswpin);
+    assert_eq!(Some(&100.0), vmstat_rate.counters.get("pgmajfault"));
+    assert_eq!(None, vmstat_rate.counters.get("nr_dirty"));
+}
+
+#[test]
+fn get_swap_devices() {
+    let swap_devices =
+        memory::get_swap_devices_from_paths("tests/data/memory/swaps", "tests/data/memory/block")
+            .unwrap();
+
+    assert_eq!(2, swap_devices.len());
+
+    let zram_swap = &swap_devices[0];
+
+    assert_eq!("/dev/zram0", zram_swap.filename);
+    assert_eq!(memory::SwapType::Partition, zram_swap.swap_type);
+    assert_eq!(4194300 * 1024, zram_swap.size);
+    assert_eq!(1024 * 1024, zram_swap.used);
+    assert_eq!(100, zram_swap.priority);
+
+    let zram = zram_swap.zram.as_ref().unwrap();
+
+    assert_eq!("zram0", zram.device);
+    assert_eq!(4294967296, zram.disk_size);
+    assert_eq!(Some("lzo-rle"), zram.algorithm.as_deref());
+    assert_eq!(10, zram.same_pages);
+    assert_eq!(Some(3), zram.huge_pages);
+    assert_eq!(Some(4.0), zram.get_compression_ratio());
+
+    let file_swap = &swap_devices[1];
+
+    assert_eq!("/swap file", file_swap.filename);
+    assert_eq!(memory::SwapType::File, file_swap.swap_type);
+    assert_eq!(-2, file_swap.priority);
+    assert!(file_swap.zram.is_none());
+}
+
+#[test]
+fn get_numa_nodes_memory() {
+    let nodes_memory = memory::get_numa_nodes_memory_from_path("tests/data/memory/node").unwrap();
+
+    assert_eq!(2, nodes_memory.len());
+
+    let node_memory = &nodes_memory[0];
+
+    assert_eq!(0, node_memory.node);
+    assert_eq!(vec![0, 1, 2, 3], node_memory.cpus);
+    assert_eq!(Some(8000000 * 1024), node_memory.mem_info.mem_total);
+    assert_eq!(Some(7900000 * 1024), node_memory.mem_info.mem_used);
+    assert_eq!(Some(300000 * 1024), node_memory.mem_info.active_file);
+    assert_eq!(Some(2), node_memory.mem_info.huge_pages_total);
+    assert_eq!(20, node_memory.numa_stat.numa_miss);
+    assert_eq!(30, node_memory.numa_stat.other_node);
+
+    assert_eq!(vec![4, 5, 6, 7], nodes_memory[1].cpus);
+    assert_eq!(Some(6000000 * 1024), nodes_memory[1].mem_info.mem_free);
+}
+
+#[test]
+fn get_huge_pages() {
+    let huge_pages = memory::get_huge_pages_from_paths(
+        "tests/data/memory/meminfo",
+        "tests/data/memory/hugepages",
+    )
+    .unwrap();
+
+    assert_eq!(4, huge_pages.total);
+    assert_eq!(2, huge_pages.free);
+    assert_eq!(0, huge_pages.reserved);
+    assert_eq!(2048 * 1024, huge_pages.size);
+    assert_eq!(None, huge_pages.hugetlb);
+
+    assert_eq!(2, huge_pages.pools.len());
+
+    let pool = &huge_pages.pools[0];
+
+    assert_eq!(2048 * 1024, pool.size);
+    assert_eq!(4, pool.total);
+    assert_eq!(2, pool.free);
+    assert_eq!(1, pool.reserved);
+
+    assert_eq!(1024 * 1024 * 1024, huge_pages.pools[1].size);
+    assert_eq!(1, huge_pages.pools[1].free);
+}
+
+#[test]
+fn get_transparent_huge_page() {
+    let thp = memory::get_transparent_huge_page_from_path("tests/data/memory/transparent_hugepage")
+        .unwrap()
+        .unwrap();
+
+    assert_eq!("madvise", thp.enabled);
+    assert_eq!("madvise", thp.defrag);
+    assert_eq!(Some("never"), thp.shmem_enabled.as_deref());
+    assert_eq!(Some(2097152), thp.pmd_size);
+    assert_eq!(Some(true), thp.use_zero_page);
+
+    assert!(thp.khugepaged.defrag);
+    assert_eq!(4096, thp.khugepaged.pages_to_scan);
+    assert_eq!(Duration::from_secs(10), thp.khugepaged.scan_sleep);
+    assert_eq!(37, thp.khugepaged.pages_collapsed);
+    assert_eq!(5, thp.khugepaged.full_scans);
+    assert_eq!(Some(256), thp.khugepaged.max_ptes_shared);
+
+    assert!(memory::get_transparent_huge_page_from_path("tests/data/memory/none")
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn get_buddy_info() {
+    let buddy_info = memory::get_buddy_info_from_path("tests/data/memory/buddyinfo").unwrap();
+
+    assert_eq!(4, buddy_info.len());
+
+    let dma = &buddy_info[0];
+
+    assert_eq!(0, dma.node);
+    assert_eq!("DMA", dma.zone);
+    assert_eq!(256 + 512 + 3 * 1024, dma.get_free_pages());
+    assert_eq!(None, dma.get_fragmentation_index(9));
+
+    let normal = &buddy_info[2];
+
+    assert_eq!("Normal", normal.zone);
+    assert_eq!(vec![100, 50, 10, 0, 0, 0, 0, 0, 0, 0, 0], normal.free_blocks);
+    assert_eq!(240, normal.get_free_pages());
+    assert_eq!(None, normal.get_fragmentation_index(2));
+    assert_eq!(Some(1.0 - 31.0 / 160.0), normal.get_fragmentation_index(3));
+    assert_eq!(None, normal.get_fragmentation_index(11));
+    assert_eq!(None, normal.get_fragmentation_index(64));
+    assert_eq!(100.0 / 240.0, normal.get_unusable_index(1));
+    assert_eq!(1.0, normal.get_unusable_index(3));
+
+    let empty = &buddy_info[3];
+
+    assert_eq!(1, empty.node);
+    assert_eq!(Some(0.0), empty.get_fragmentation_index(3));
+    assert_eq!(1.0, empty.get_unusable_index(3));
+}
+
+#[test]
+fn get_page_type_info() {
+    let page_type_info =
+        memory::get_page_type_info_from_path("tests/data/memory/pagetypeinfo").unwrap();
+
+    assert_eq!(9, page_type_info.page_block_order);
+    assert_eq!(512, page_type_info.pages_per_block);
+    assert_eq!(3, page_type_info.zones.len());
+
+    let normal = &page_type_info.zones[2];
+
+    assert_eq!(0, normal.node);
+    assert_eq!("Normal", normal.zone);
+    assert_eq!(5, normal.free_blocks.len());
+    assert_eq!(vec![1, 27, 21, 5, 6, 3, 1, 1, 1, 0, 0], normal.free_blocks["Unmovable"]);
+    assert_eq!(Some(&1134), normal.page_blocks.get("Movable"));
+    assert_eq!(Some(&24), normal.page_blocks.get("Reclaimable"));
+}
+
+#[test]
+fn get_top_slab_caches() {
+    let page_size = page_size::get() as u64;
+
+    let slab_caches = memory::get_slab_caches_from_path("tests/data/memory/slabinfo").unwrap();
+
+    assert_eq!(6, slab_caches.len());
+
+    let ext4_inode_cache = &slab_caches[0];
+
+    assert_eq!("ext4_inode_cache", ext4_inode_cache.name);
+    assert_eq!(8274, ext4_inode_cache.active_objects);
+    assert_eq!(1120, ext4_inode_cache.object_size);
+    assert_eq!(4, ext4_inode_cache.pages_per_slab);
+    assert_eq!(591, ext4_inode_cache.slabs);
+    assert_eq!(591 * 4 * page_size, ext4_inode_cache.size);
+    assert_eq!(8274 * 1120, ext4_inode_cache.get_active_size());
+
+    let top_slab_caches =
+        memory::get_top_slab_caches_from_path("tests/data/memory/slabinfo", 3).unwrap();
+
+    assert_eq!(
+        vec!["buffer_head", "ext4_inode_cache", "dentry"],
+        top_slab_caches.iter().map(|slab_cache| slab_cache.name.as_str()).collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,341 @@
+use std::{
+    io::ErrorKind,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+use mprober_lib::{
+    network::{self, NetworkAddress, NetworkAddressScope, NetworkWireless},
+    scanner_rust::ScannerError,
+};
+use regex::Regex;
+
+#[test]
+fn get_network_wireless_from_path() {
+    let network_wireless =
+        network::get_network_wireless_from_path("tests/data/network/wireless").unwrap();
+
+    assert_eq!(2, network_wireless.len());
+
+    assert_eq!(
+        &NetworkWireless {
+            status:          0,
+            link_quality:    54,
+            signal_level:    -56,
+            noise_level:     -256,
+            discarded_nwid:  0,
+            discarded_crypt: 0,
+            discarded_frag:  0,
+            discarded_retry: 17,
+            discarded_misc:  12,
+            missed_beacon:   3,
+        },
+        network_wireless.get("wlan0").unwrap()
+    );
+
+    assert_eq!(
+        &NetworkWireless {
+            status:          4,
+            link_quality:    70,
+            signal_level:    -40,
+            noise_level:     -92,
+            discarded_nwid:  1,
+            discarded_crypt: 2,
+            discarded_frag:  3,
+            discarded_retry: 4,
+            discarded_misc:  5,
+            missed_beacon:   6,
+        },
+        network_wireless.get("wlp3s0").unwrap()
+    );
+}
+
+#[test]
+fn get_network_wireless_from_path_without_interfaces() {
+    assert!(network::get_network_wireless_from_path("tests/data/network/wireless_empty")
+        .unwrap()
+        .is_empty());
+
+    assert!(network::get_network_wireless_from_path("tests/data/network/not_exist")
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn get_socket_stat_from_paths() {
+    let page_size = page_size::get() as u64;
+
+    let socket_stat =
+        network::get_socket_stat_from_paths("tests/data/network/net", "tests/data/network/ipv4")
+            .unwrap();
+
+    assert_eq!(812, socket_stat.sockets_used);
+    assert_eq!(42, socket_stat.tcp.inuse);
+    assert_eq!(3, socket_stat.tcp.orphan);
+    assert_eq!(120, socket_stat.tcp.tw);
+    assert_eq!(25 * page_size, socket_stat.tcp.mem);
+    assert_eq!(4 * page_size, socket_stat.udp.mem);
+    assert_eq!(1, socket_stat.raw_inuse);
+    assert_eq!(12, socket_stat.tcp6_inuse);
+    assert_eq!(2, socket_stat.raw6_inuse);
+    assert_eq!(2048, socket_stat.frag6.memory);
+
+    assert_eq!(
+        [18585 * page_size, 24781 * page_size, 37170 * page_size],
+        socket_stat.limits.tcp_mem
+    );
+    assert_eq!(74340 * page_size, socket_stat.limits.udp_mem[2]);
+    assert_eq!(8192, socket_stat.limits.tcp_max_orphans);
+    assert_eq!(240, socket_stat.limits.tcp_max_tw_buckets);
+
+    assert_eq!(25.0 / 37170.0, socket_stat.tcp_memory_utilization());
+    assert_eq!(4.0 / 74340.0, socket_stat.udp_memory_utilization());
+    assert_eq!(3.0 / 8192.0, socket_stat.tcp_orphan_utilization());
+    assert_eq!(0.5, socket_stat.tcp_time_wait_utilization());
+
+    // IPv6 is disabled
+    let socket_stat = network::get_socket_stat_from_paths(
+        "tests/data/network/net_no_ipv6",
+        "tests/data/network/ipv4",
+    )
+    .unwrap();
+
+    assert_eq!(42, socket_stat.tcp.inuse);
+    assert_eq!(0, socket_stat.tcp6_inuse);
+}
+
+#[test]
+fn get_connection_tracking_from_paths() {
+    let connection_tracking = network::get_connection_tracking_from_paths(
+        "tests/data/network/netfilter/nf_conntrack_count",
+        "tests/data/network/netfilter/nf_conntrack_max",
+    )
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(6000, connection_tracking.count);
+    assert_eq!(262144, connection_tracking.max);
+    assert_eq!(6000.0 / 262144.0, connection_tracking.utilization());
+
+    assert!(network::get_connection_tracking_from_paths(
+        "tests/data/network/netfilter/none",
+        "tests/data/network/netfilter/nf_conntrack_max",
+    )
+    .unwrap()
+    .is_none());
+}
+
+fn get_interfaces(network_filter: &network::NetworkFilter) -> Vec<String> {
+    network::get_networks_from_paths(
+        "tests/data/network/dev",
+        "tests/data/network/wireless",
+        "tests/data/network/class_net",
+        network_filter,
+    )
+    .unwrap()
+    .into_iter()
+    .map(|network| network.interface)
+    .collect()
+}
+
+#[test]
+fn get_networks_from_paths() {
+    let networks = network::get_networks_from_paths(
+        "tests/data/network/dev",
+        "tests/data/network/wireless",
+        "tests/data/network/class_net",
+        &network::NetworkFilter::default(),
+    )
+    .unwrap();
+
+    assert_eq!(6, networks.len());
+
+    let eth0 = &networks[1];
+
+    assert_eq!("eth0", eth0.interface);
+    assert_eq!(9876543, eth0.stat.receive_bytes);
+    assert_eq!(1234567, eth0.stat.transmit_bytes);
+    assert!(eth0.addresses.is_empty());
+    assert!(eth0.wireless.is_none());
+
+    let wlan0 = &networks[3];
+
+    assert_eq!("wlan0", wlan0.interface);
+    assert_eq!(54, wlan0.wireless.as_ref().unwrap().link_quality);
+}
+
+#[test]
+fn network_filter() {
+    let interface_filter = Regex::new("^(eth|wlan)").unwrap();
+    let interface_exclusion_filter = Regex::new("^(lo|docker|veth)").unwrap();
+
+    assert_eq!(
+        vec!["eth0", "eth1", "wlan0"],
+        get_interfaces(&network::NetworkFilter {
+            interface_filter: Some(&interface_filter),
+            ..network::NetworkFilter::default()
+        })
+    );
+
+    assert_eq!(
+        vec!["eth0", "eth1", "wlan0"],
+        get_interfaces(&network::NetworkFilter {
+            interface_exclusion_filter: Some(&interface_exclusion_filter),
+            ..network::NetworkFilter::default()
+        })
+    );
+
+    assert_eq!(
+        vec!["eth0", "eth1", "wlan0"],
+        get_interfaces(&network::NetworkFilter {
+            physical_only: true,
+            ..network::NetworkFilter::default()
+        })
+    );
+
+    assert_eq!(
+        vec!["lo", "eth0", "wlan0", "docker0", "veth1a2b3c"],
+        get_interfaces(&network::NetworkFilter {
+            up_only: true,
+            ..network::NetworkFilter::default()
+        })
+    );
+
+    let interface_filter = Regex::new("^(eth|docker)").unwrap();
+    let interface_exclusion_filter = Regex::new("^docker").unwrap();
+
+    assert_eq!(
+        vec!["eth0"],
+        get_interfaces(&network::NetworkFilter {
+            interface_filter:           Some(&interface_filter),
+            interface_exclusion_filter: Some(&interface_exclusion_filter),
+            physical_only:              true,
+            up_only:                    true,
+        })
+    );
+}
+
+#[test]
+fn get_network_namespaces_from_path() {
+    let network_namespaces =
+        network::get_network_namespaces_from_path("tests/data/network/proc").unwrap();
+
+    assert_eq!(2, network_namespaces.len());
+
+    let pids = network_namespaces.iter().map(|e| e.pids.clone()).collect::<Vec<_>>();
+
+    assert!(pids.contains(&vec![100, 101]));
+    assert!(pids.contains(&vec![102]));
+
+    assert_eq!(
+        network::get_network_namespace_id_from_path("tests/data/network/proc/101/ns/net").unwrap(),
+        network::get_network_namespace_id_from_path("tests/data/network/proc/100/ns/net").unwrap()
+    );
+}
+
+#[test]
+fn get_networks_by_pid() {
+    let pid = std::process::id();
+
+    let network_namespace_id = network::get_network_namespace_id(pid).unwrap();
+
+    assert!(network::get_network_namespaces()
+        .unwrap()
+        .into_iter()
+        .any(|e| e.id == network_namespace_id && e.pids.contains(&pid)));
+
+    let mut interfaces =
+        network::get_networks().unwrap().into_iter().map(|e| e.interface).collect::<Vec<_>>();
+    let mut interfaces_by_pid = network::get_networks_by_pid(pid)
+        .unwrap()
+        .into_iter()
+        .map(|e| e.interface)
+        .collect::<Vec<_>>();
+
+    interfaces.sort();
+    interfaces_by_pid.sort();
+
+    assert_eq!(interfaces, interfaces_by_pid);
+
+    // `/proc/1/ns/net` may not be accessible, which only makes addresses unavailable
+    if std::fs::metadata("/proc/1/net/dev").is_ok() {
+        assert!(network::get_networks_by_pid(1).is_ok());
+    }
+
+    // the process does not exist
+    match network::get_networks_by_pid(u32::MAX) {
+        Err(ScannerError::IOError(err)) => assert_eq!(ErrorKind::NotFound, err.kind()),
+        other => panic!("{other:?}"),
+    }
+}
+
+#[test]
+fn network_address_new() {
+    let ipv4 = |a, b, c, d| IpAddr::V4(Ipv4Addr::new(a, b, c, d));
+
+    assert_eq!(
+        NetworkAddress {
+            address:       ipv4(192, 168, 1, 10),
+            prefix_length: 24,
+            broadcast:     Some(ipv4(192, 168, 1, 255)),
+            scope:         NetworkAddressScope::Global,
+        },
+        NetworkAddress::new(
+            ipv4(192, 168, 1, 10),
+            Some(ipv4(255, 255, 255, 0)),
+            Some(ipv4(192, 168, 1, 255))
+        )
+    );
+
+    let loopback = NetworkAddress::new(ipv4(127, 0, 0, 1), Some(ipv4(255, 0, 0, 0)), None);
+
+    assert_eq!(8, loopback.prefix_length);
+    assert_eq!(NetworkAddressScope::Host, loopback.scope);
+
+    // the kernel assigns the global scope to IPv4 link-local addresses by default
+    let link_local = NetworkAddress::new(ipv4(169, 254, 3, 4), Some(ipv4(255, 255, 0, 0)), None);
+
+    assert_eq!(16, link_local.prefix_length);
+    assert_eq!(NetworkAddressScope::Global, link_local.scope);
+
+    let no_netmask = NetworkAddress::new(ipv4(10, 0, 0, 1), None, None);
+
+    assert_eq!(0, no_netmask.prefix_length);
+
+    let ipv6 = |s: &str| IpAddr::V6(s.parse::<Ipv6Addr>().unwrap());
+    let ipv6_netmask =
+        |prefix_length: u32| IpAddr::V6(Ipv6Addr::from(u128::MAX << (128 - prefix_length)));
+
+    assert_eq!(
+        NetworkAddress {
+            address:       ipv6("fe80::1"),
+            prefix_length: 64,
+            broadcast:     None,
+            scope:         NetworkAddressScope::Link,
+        },
+        NetworkAddress::new(ipv6("fe80::1"), Some(ipv6_netmask(64)), Some(ipv6("ff02::1")))
+    );
+
+    let cases = [
+        ("::1", 128, NetworkAddressScope::Host),
+        ("fec0::1", 10, NetworkAddressScope::Site),
+        ("2001:db8::1", 48, NetworkAddressScope::Global),
+        ("fd00::1", 8, NetworkAddressScope::Global),
+    ];
+
+    for (address, prefix_length, scope) in cases {
+        let network_address =
+            NetworkAddress::new(ipv6(address), Some(ipv6_netmask(prefix_length)), None);
+
+        assert_eq!(prefix_length as u8, network_address.prefix_length);
+        assert_eq!(scope, network_address.scope);
+        assert!(network_address.is_ipv6());
+    }
+}
+
+#[test]
+fn get_interface_of_label() {
+    assert_eq!("eth0", network::get_interface_of_label("eth0"));
+    assert_eq!("eth0", network::get_interface_of_label("eth0:1"));
+    assert_eq!("br-lan", network::get_interface_of_label("br-lan:backup"));
+    assert_eq!("", network::get_interface_of_label(""));
+}
@@ -0,0 +1,18 @@
+use mprober_lib::pressure;
+
+#[test]
+fn get_pressure() {
+    let pressure = pressure::get_pressure_from_path("tests/data/pressure/cpu").unwrap();
+
+    let some = pressure.some.unwrap();
+
+    assert!((some.avg10 - 0.038).abs() < 1e-9);
+    assert!((some.avg300 - 0.0536).abs() < 1e-9);
+    assert_eq!(70391259, some.total);
+    assert_eq!(0, pressure.full.unwrap().total);
+
+    let pressure = pressure::get_pressure_from_path("tests/data/pressure/irq").unwrap();
+
+    assert!(pressure.some.is_none());
+    assert_eq!(12345, pressure.full.unwrap().total);
+}
@@ -0,0 +1,54 @@
+use std::time::Duration;
+
+use mprober_lib::process;
+
+#[test]
+fn get_process_sched_stat() {
+    let process_sched_stat =
+        process::get_process_sched_stat_from_path("tests/data/process/schedstat").unwrap();
+
+    assert_eq!(1843201234, process_sched_stat.run_time);
+    assert_eq!(52341987, process_sched_stat.wait_time);
+    assert_eq!(4211, process_sched_stat.timeslices);
+
+    let mut after = process_sched_stat.clone();
+
+    after.wait_time += 3_000_000;
+    after.timeslices += 4;
+
+    let sched_latency =
+        process_sched_stat.compute_sched_latency(&after, Duration::from_millis(500));
+
+    assert_eq!(Duration::from_micros(750), sched_latency.average_wait);
+    assert_eq!(4, sched_latency.timeslices);
+    assert_eq!(0.006, sched_latency.wait_rate);
+}
+
+#[test]
+fn get_process_stat_from_paths() {
+    let page_size = page_size::get();
+
+    let process_stat = process::get_process_stat_from_paths(
+        "tests/data/process/stat",
+        "tests/data/process/statm_normal",
+    )
+    .unwrap();
+
+    assert_eq!("firefox", process_stat.comm);
+    assert_eq!(22887, process_stat.ppid);
+    assert_eq!(Some(22932), process_stat.tpgid);
+    assert_eq!(3, process_stat.num_threads);
+    assert_eq!(5, process_stat.processor);
+    assert_eq!(313 * page_size, process_stat.rss);
+    assert_eq!(120 * page_size, process_stat.shared);
+    assert_eq!(193 * page_size, process_stat.rss_anon);
+
+    // `shared` in `statm` is larger than `rss` in `stat`
+    let process_stat =
+        process::get_process_stat_from_paths("tests/data/process/stat", "tests/data/process/statm")
+            .unwrap();
+
+    assert_eq!(313 * page_size, process_stat.rss);
+    assert_eq!(329 * page_size, process_stat.shared);
+    assert_eq!(0, process_stat.rss_anon);
+}